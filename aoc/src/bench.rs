@@ -0,0 +1,179 @@
+use common::{input, DynSolution};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// The stages of a day which are timed separately
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part1"),
+            Stage::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+// Timings for one stage of one day, over every iteration
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    // Summarise the durations of every iteration of a stage
+    //
+    // Note: for an even number of iterations, the median is the mean of the middle two
+    pub fn new(day: u8, stage: Stage, mut durations: Vec<Duration>) -> Self {
+        assert!(!durations.is_empty(), "Cannot summarise zero iterations");
+        durations.sort();
+        let middle = durations.len() / 2;
+        let median = match durations.len() % 2 {
+            0 => (durations[middle - 1] + durations[middle]) / 2,
+            _ => durations[middle],
+        };
+        Timing {
+            day,
+            stage,
+            iterations: durations.len(),
+            min: durations[0],
+            median,
+            max: durations[durations.len() - 1],
+        }
+    }
+}
+
+// Time a closure over a number of iterations
+fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let result = f();
+            let elapsed = start.elapsed();
+            drop(result); // Don't count dropping the result
+            elapsed
+        })
+        .collect::<Vec<_>>()
+}
+
+// Time parsing, part 1 and part 2 of a day
+//
+// The puzzle input is read in once up front, so that only parsing is timed
+// and not IO (which also means stdin can be benchmarked).
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    name: &str,
+    iterations: usize,
+) -> Result<Vec<Timing>, input::Error> {
+    let day = solution.day();
+    let file = match name {
+        input::STDIN => "<stdin>".to_string(),
+        _ => solution.resolve_input(name)?.display().to_string(),
+    };
+    let text = input::read(solution.open_input(name)?)?;
+    let parsed = solution
+        .parse(&text)
+        .map_err(|error| error.in_file(&file))?;
+    let parse = time(iterations, || solution.parse(&text));
+    let part1 = time(iterations, || solution.part(parsed.as_ref(), 1));
+    let part2 = time(iterations, || solution.part(parsed.as_ref(), 2));
+    Ok(vec![
+        Timing::new(day, Stage::Parse, parse),
+        Timing::new(day, Stage::Part1, part1),
+        Timing::new(day, Stage::Part2, part2),
+    ])
+}
+
+// Format a duration to a sensible precision for a human to read
+pub fn human(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+// Print a table of timings
+pub fn print(timings: &[Timing]) {
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "Day", "Stage", "Min", "Median", "Max"
+    );
+    for timing in timings {
+        println!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+            timing.day,
+            timing.stage,
+            human(timing.min),
+            human(timing.median),
+            human(timing.max)
+        );
+    }
+}
+
+// Format timings as CSV, in nanoseconds, so that they can be compared across commits
+pub fn csv(timings: &[Timing]) -> String {
+    let mut csv = String::from("day,stage,iterations,min_ns,median_ns,max_ns\n");
+    for timing in timings {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            timing.day,
+            timing.stage,
+            timing.iterations,
+            timing.min.as_nanos(),
+            timing.median.as_nanos(),
+            timing.max.as_nanos()
+        ));
+    }
+    csv
+}
+
+pub fn write(path: &Path, timings: &[Timing]) -> io::Result<()> {
+    fs::write(path, csv(timings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarise() {
+        let durations = [30, 10, 20, 40].map(Duration::from_nanos).to_vec();
+        let timing = Timing::new(1, Stage::Parse, durations);
+        assert_eq!(timing.iterations, 4);
+        assert_eq!(timing.min, Duration::from_nanos(10));
+        assert_eq!(timing.median, Duration::from_nanos(25));
+        assert_eq!(timing.max, Duration::from_nanos(40));
+        assert_eq!(
+            csv(&[timing]),
+            "day,stage,iterations,min_ns,median_ns,max_ns\n1,parse,4,10,25,40\n"
+        );
+    }
+
+    #[test]
+    fn units() {
+        let human = |nanos| human(Duration::from_nanos(nanos));
+        assert_eq!(human(999), "999ns");
+        assert_eq!(human(1_000), "1.0µs");
+        assert_eq!(human(2_652_500), "2.7ms");
+        assert_eq!(human(12_200_000), "12.2ms");
+        assert_eq!(human(999_949_999), "999.9ms");
+        assert_eq!(human(1_500_000_000), "1.50s");
+    }
+}
@@ -0,0 +1,30 @@
+use common::DynSolution;
+
+pub fn all() -> Vec<Box<dyn DynSolution>> {
+    vec![
+        Box::new(day_01::Day01),
+        Box::new(day_02::Day02),
+        Box::new(day_03::Day03),
+        Box::new(day_04::Day04),
+        Box::new(day_05::Day05),
+        Box::new(day_06::Day06::default()),
+        Box::new(day_07::Day07::default()),
+        Box::new(day_08::Day08),
+        Box::new(day_09::Day09::default()),
+        Box::new(day_10::Day10),
+        Box::new(day_11::Day11::default()),
+        Box::new(day_12::Day12),
+        Box::new(day_13::Day13),
+        Box::new(day_14::Day14::default()),
+        Box::new(day_15::Day15::default()),
+        // New days are added above this line by `aoc new`
+    ]
+}
+
+pub fn find(day: u8) -> Option<Box<dyn DynSolution>> {
+    all().into_iter().find(|solution| solution.day() == day)
+}
+
+pub fn name(day: u8) -> String {
+    format!("day_{:02}", day)
+}
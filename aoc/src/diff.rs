@@ -0,0 +1,59 @@
+use crate::days;
+use common::config::Config;
+use common::differential::Comparison;
+use common::generate::{Generated, Rng};
+
+// Compare a day's alternatives on a puzzle input, set up from the config file
+pub fn compare_input(day: u8, config: &Config, name: &str) -> Result<Vec<Comparison>, String> {
+    let mut solution = days::find(day).ok_or_else(|| format!("Day {day} is not available"))?;
+    config.configure_dyn(solution.as_mut(), name)?;
+    let input = solution
+        .parse_input(name)
+        .map_err(|error| error.to_string())?;
+    Ok(solution.compare(input.as_ref()))
+}
+
+// Compare a day's alternatives on a puzzle input generated from a seed, or
+// `None` if the day cannot generate puzzle inputs
+pub fn compare_generated(
+    day: u8,
+    seed: u64,
+    size: usize,
+) -> Result<Option<(Generated, Vec<Comparison>)>, String> {
+    let mut solution = days::find(day).ok_or_else(|| format!("Day {day} is not available"))?;
+    let Some(generated) = solution.generate(&mut Rng::new(seed), size) else {
+        return Ok(None);
+    };
+    for (name, value) in &generated.params {
+        solution.set_param(name, value)?;
+    }
+    let input = solution
+        .parse(&generated.input)
+        .map_err(|error| format!("Cannot parse the generated input: {error}"))?;
+    let comparisons = solution.compare(input.as_ref());
+    Ok(Some((generated, comparisons)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_days() {
+        let comparisons = compare_input(8, &Config::default(), "test_input.txt").unwrap();
+        assert_eq!(comparisons.len(), 2);
+        assert!(comparisons.iter().all(Comparison::agrees));
+
+        let (generated, comparisons) = compare_generated(15, 1, 10).unwrap().unwrap();
+        assert_eq!(
+            generated.params[1],
+            ("bounds".to_string(), "0,10".to_string())
+        );
+        assert!(comparisons.iter().all(Comparison::agrees));
+
+        assert!(compare_input(1, &Config::default(), "test_input.txt")
+            .unwrap()
+            .is_empty());
+        assert!(compare_generated(99, 1, 10).is_err());
+    }
+}
@@ -0,0 +1,250 @@
+use common::input;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const YEAR: u16 = 2022;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Environment variable holding the value of the `session` cookie from
+// logging in to Advent of Code in a browser
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+// Advent of Code asks that automated requests say who they are from
+const USER_AGENT: &str = "github.com/garrett-may/aoc-2022 by garrett.ls.may@gmail.com";
+
+// The name puzzle inputs are saved as, which is what `aoc run` looks for by default
+pub const INPUT_NAME: &str = "input.txt";
+
+// Downloads puzzle inputs from Advent of Code, or anything serving the
+// same paths (e.g. a local stand-in server for testing)
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    // Note: the session is only needed once something has to be downloaded
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    // Find the session token from a file if given, otherwise from the environment
+    pub fn session(file: Option<&Path>) -> io::Result<Option<String>> {
+        let session = match file {
+            Some(file) => Some(fs::read_to_string(file)?),
+            None => env::var(SESSION_VAR).ok(),
+        };
+        Ok(session
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty()))
+    }
+
+    pub fn input_url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/input", self.base_url)
+    }
+
+    pub fn answer_url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/answer", self.base_url)
+    }
+
+    fn cookie(&self, action: &str) -> io::Result<String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={session}")),
+            None => Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!(
+                    "Cannot {action} without a session token; set {SESSION_VAR} or use --session-file"
+                ),
+            )),
+        }
+    }
+
+    // Send a request, returning the body of the response
+    fn send(
+        &self,
+        url: &str,
+        action: &str,
+        f: impl FnOnce(String) -> Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    ) -> io::Result<String> {
+        let to_io = |error: ureq::Error| match error {
+            ureq::Error::StatusCode(400 | 401) => io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("Unable to {action} at {url}: the session token was rejected"),
+            ),
+            ureq::Error::StatusCode(404) => io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Unable to {action} at {url}: not found (is the puzzle unlocked yet?)"),
+            ),
+            error => io::Error::other(format!("Unable to {action} at {url}: {error}")),
+        };
+        f(self.cookie(action)?)
+            .map_err(to_io)?
+            .body_mut()
+            .read_to_string()
+            .map_err(to_io)
+    }
+
+    pub fn download(&self, day: u8) -> io::Result<String> {
+        let url = self.input_url(day);
+        let action = format!("download day {day}");
+        let input = self.send(&url, &action, |cookie| {
+            ureq::get(&url)
+                .header("Cookie", cookie)
+                .header("User-Agent", USER_AGENT)
+                .call()
+        })?;
+        if input.trim().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Unable to {action} at {url}: the puzzle input was empty"),
+            ));
+        }
+        Ok(input)
+    }
+
+    // Submit an answer, returning the page describing whether it was right
+    pub fn post_answer(&self, day: u8, part: u8, answer: &str) -> io::Result<String> {
+        let url = self.answer_url(day);
+        let action = format!("submit day {day} part {part}");
+        self.send(&url, &action, |cookie| {
+            ureq::post(&url)
+                .header("Cookie", cookie)
+                .header("User-Agent", USER_AGENT)
+                .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// Fetch a day's puzzle input, unless the runner can already find one
+//
+// Inputs never change, so an existing input is never downloaded again: that is
+// an `input.txt` (encrypted or not, see `vault`) in one of the day's own
+// directories, `$AOC_INPUT_DIR/day_NN/` or its `res/`, as `input::resolve` finds
+// it. Any `input.txt` in the current working directory belongs to some other
+// day, if any, so is never counted. `input_dir` stands in for `$AOC_INPUT_DIR`,
+// and the input is downloaded to `input::save_dir`. It is written to a temporary
+// file first, so that a failed download cannot leave a partial input behind to
+// be cached.
+pub fn fetch(
+    client: &Client,
+    day: u8,
+    res_dir: &str,
+    input_dir: Option<&Path>,
+) -> io::Result<Fetched> {
+    if let Ok(path) = input::resolve_in(input_dir, day, res_dir, INPUT_NAME) {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = client.download(day)?;
+    let dir = input::save_dir_in(input_dir, day, res_dir);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(INPUT_NAME);
+    let partial = dir.join(format!("{INPUT_NAME}.partial"));
+    fs::write(&partial, input)?;
+    fs::rename(&partial, &path)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::serve_once;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetch_then_cache() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let client = Client::new(&base_url, Some("abc123".to_string()));
+        let dir = temp_dir("cache");
+
+        let path = dir.join(INPUT_NAME);
+        let res_dir = dir.to_str().unwrap();
+        assert_eq!(
+            fetch(&client, 1, res_dir, None).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.to_lowercase().contains("cookie: session=abc123"));
+
+        // The server has gone, so this can only succeed without a request
+        assert_eq!(
+            fetch(&client, 1, res_dir, None).unwrap(),
+            Fetched::Cached(path)
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_rejected() {
+        let (base_url, server) = serve_once("400 Bad Request", "");
+        let client = Client::new(&base_url, Some("expired".to_string()));
+        let dir = temp_dir("rejected");
+
+        let res_dir = dir.to_str().unwrap();
+        let error = fetch(&client, 2, res_dir, None).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        assert!(!dir.join(INPUT_NAME).exists());
+        server.join().unwrap();
+
+        let client = Client::new(&base_url, None);
+        assert_eq!(
+            fetch(&client, 2, res_dir, None).unwrap_err().kind(),
+            io::ErrorKind::PermissionDenied
+        );
+    }
+
+    #[test]
+    fn cached_outside_input_dir() {
+        // Without a session, anything which is not cached fails to download
+        let client = Client::new("http://127.0.0.1:9", None);
+        let dir = temp_dir("elsewhere");
+        let (input_dir, res_dir) = (dir.join("inputs"), dir.join("res"));
+        fs::create_dir_all(&res_dir).unwrap();
+        let res_dir = res_dir.to_str().unwrap();
+
+        // An encrypted puzzle input in the day's `res/`, while saving to `$AOC_INPUT_DIR`
+        let encrypted = Path::new(res_dir).join("input.txt.enc");
+        fs::write(&encrypted, "").unwrap();
+        assert_eq!(
+            fetch(&client, 3, res_dir, Some(&input_dir)).unwrap(),
+            Fetched::Cached(encrypted.clone())
+        );
+        assert!(!input_dir.exists());
+
+        // A puzzle input in `$AOC_INPUT_DIR` is found first
+        let saved = input_dir.join("day_03").join(INPUT_NAME);
+        fs::create_dir_all(saved.parent().unwrap()).unwrap();
+        fs::write(&saved, "vJrwpWtwJgWrhcsFMMfFFhFp\n").unwrap();
+        assert_eq!(
+            fetch(&client, 3, res_dir, Some(&input_dir)).unwrap(),
+            Fetched::Cached(saved)
+        );
+
+        fs::remove_file(&encrypted).unwrap();
+        assert_eq!(
+            fetch(&client, 4, res_dir, Some(&input_dir))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::PermissionDenied
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
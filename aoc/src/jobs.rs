@@ -0,0 +1,221 @@
+use crate::bench;
+use crate::output::{self, Record};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// How running a day went
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok(Vec<Record>),
+    Error(String),
+    Panicked(String),
+}
+
+// A day run by one of the jobs, and how long it took (including parsing)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayRun {
+    pub day: u8,
+    pub status: Status,
+    pub time: Duration,
+}
+
+// The message a thread panicked with, which is almost always a string
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "Unknown panic".to_string(),
+    }
+}
+
+// Run each of the days on up to `jobs` threads at once, with each thread
+// taking the next day not yet run as soon as it is free
+//
+// A day which panics is caught on its own thread, so it never stops the rest
+// of the days from running. The runs are given back in the order of `days`.
+pub fn run_days(
+    days: &[u8],
+    jobs: usize,
+    f: impl Fn(u8) -> Result<Vec<Record>, String> + Sync,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let start = Instant::now();
+                    let status = match panic::catch_unwind(AssertUnwindSafe(|| f(day))) {
+                        Ok(Ok(records)) => Status::Ok(records),
+                        Ok(Err(error)) => Status::Error(error),
+                        Err(payload) => Status::Panicked(panic_message(payload.as_ref())),
+                    };
+                    let run = DayRun {
+                        day,
+                        status,
+                        time: start.elapsed(),
+                    };
+                    runs.lock()
+                        .expect("No job panics holding the lock")
+                        .push(run);
+                }
+            });
+        }
+    });
+    let mut runs = runs.into_inner().expect("No job panics holding the lock");
+    runs.sort_by_key(|run| days.iter().position(|&day| day == run.day));
+    runs
+}
+
+// An answer as it fits in a table cell; multi-line answers (e.g. day 10's
+// image) are printed in full below the table instead
+fn cell(record: &Record) -> String {
+    match record.answer.lines().count() {
+        0 | 1 => record.answer.clone(),
+        lines => format!("({lines} lines, below)"),
+    }
+}
+
+// A table of every day's answers, status and time, followed by any multi-line
+// answers, and the totals
+pub fn summary(runs: &[DayRun], wall_time: Duration) -> String {
+    let rows = runs
+        .iter()
+        .map(|run| {
+            let (status, columns) = match &run.status {
+                Status::Ok(records) => ("ok", records.iter().map(cell).collect::<Vec<_>>()),
+                Status::Error(error) => (
+                    "error",
+                    vec![error.lines().next().unwrap_or("").to_string()],
+                ),
+                Status::Panicked(message) => ("panicked", vec![message.clone()]),
+            };
+            (run.day, status, bench::human(run.time), columns)
+        })
+        .collect::<Vec<_>>();
+    // Only answers are lined up in columns, as errors can be any length
+    let width = runs
+        .iter()
+        .filter_map(|run| match &run.status {
+            Status::Ok(records) => Some(records.iter().map(|record| cell(record).chars().count())),
+            _ => None,
+        })
+        .flatten()
+        .max()
+        .unwrap_or(0)
+        .max("Part 1".len());
+    let mut table = format!(
+        "{:>3}  {:<8}  {:>10}  {:<width$}  {}\n",
+        "Day", "Status", "Time", "Part 1", "Part 2"
+    );
+    for (day, status, time, columns) in rows {
+        let columns = columns
+            .iter()
+            .map(|column| format!("{column:<width$}"))
+            .collect::<Vec<_>>();
+        let line = format!("{day:>3}  {status:<8}  {time:>10}  {}", columns.join("  "));
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    for run in runs {
+        if let Status::Ok(records) = &run.status {
+            for record in records.iter().filter(|record| record.answer.contains('\n')) {
+                table.push_str(&format!("\n{}\n", output::text(record)));
+            }
+        }
+    }
+    let failed = runs
+        .iter()
+        .filter(|run| !matches!(run.status, Status::Ok(_)))
+        .count();
+    let total = runs.iter().map(|run| run.time).sum::<Duration>();
+    table.push_str(&format!(
+        "\n{} days, {} failed, in {} ({} across every day)\n",
+        runs.len(),
+        failed,
+        bench::human(wall_time),
+        bench::human(total)
+    ));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, part: u8, answer: &str) -> Record {
+        Record {
+            day,
+            part,
+            input: "input.txt".to_string(),
+            answer: answer.to_string(),
+            time: Duration::from_nanos(10),
+        }
+    }
+
+    #[test]
+    fn isolate_days() {
+        let days = [1, 2, 3, 4, 5];
+        let runs = run_days(&days, 3, |day| match day {
+            2 => Err("Cannot find puzzle input".to_string()),
+            4 => panic!("day {day} went wrong"),
+            _ => Ok(vec![record(day, 1, &day.to_string())]),
+        });
+        assert_eq!(runs.iter().map(|run| run.day).collect::<Vec<_>>(), days);
+        assert_eq!(runs[0].status, Status::Ok(vec![record(1, 1, "1")]));
+        assert_eq!(
+            runs[1].status,
+            Status::Error("Cannot find puzzle input".to_string())
+        );
+        assert_eq!(
+            runs[3].status,
+            Status::Panicked("day 4 went wrong".to_string())
+        );
+        assert_eq!(runs[4].status, Status::Ok(vec![record(5, 1, "5")]));
+
+        // More jobs than days, or none at all, still runs every day
+        assert_eq!(run_days(&days, 16, |_| Ok(vec![])).len(), 5);
+        assert_eq!(run_days(&days, 0, |_| Ok(vec![])).len(), 5);
+    }
+
+    #[test]
+    fn summarise() {
+        let runs = [
+            DayRun {
+                day: 5,
+                status: Status::Ok(vec![record(5, 1, "CMZ"), record(5, 2, "MCD")]),
+                time: Duration::from_nanos(1500),
+            },
+            DayRun {
+                day: 10,
+                status: Status::Ok(vec![record(10, 1, "13140"), record(10, 2, "#.\n.#")]),
+                time: Duration::from_micros(20),
+            },
+            DayRun {
+                day: 11,
+                status: Status::Panicked("attempt to multiply with overflow".to_string()),
+                time: Duration::from_millis(30),
+            },
+        ];
+        assert_eq!(
+            summary(&runs, Duration::from_millis(40)),
+            concat!(
+                "Day  Status          Time  Part 1            Part 2\n",
+                "  5  ok             1.5µs  CMZ               MCD\n",
+                " 10  ok            20.0µs  13140             (2 lines, below)\n",
+                " 11  panicked      30.0ms  attempt to multiply with overflow\n",
+                "\n",
+                "Day 10, part 2:\n#.\n.#\n",
+                "\n",
+                "3 days, 1 failed, in 40.0ms (30.0ms across every day)\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,1067 @@
+mod bench;
+mod days;
+mod diff;
+mod fetch;
+mod jobs;
+mod memory;
+#[cfg(test)]
+mod mock;
+mod new;
+mod output;
+mod repl;
+mod submit;
+mod vis;
+#[cfg(target_os = "linux")]
+mod watch;
+
+use clap::{Args, Parser, Subcommand};
+use common::config::Config;
+use common::differential::Comparison;
+use common::generate::Rng;
+use common::visualise::Frames;
+use common::{answers, input, vault, DynSolution};
+use output::{Format, Record};
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use vis::{FrameFormat, Output};
+
+// Only counts allocations for `run --alloc` (see `memory::measure`)
+#[cfg(feature = "alloc-profile")]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// File of parameters for each day (e.g. day 15's row); defaults to `$AOC_CONFIG`,
+    /// or `aoc.toml` at the root of the repository
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for a day, or for every day
+    Run(RunArgs),
+    /// Time parsing, part 1 and part 2 of a day, or of every day
+    Bench(BenchArgs),
+    /// List the available days
+    List,
+    /// Create a new day from a template, and register it with the runner
+    New(NewArgs),
+    /// Download the puzzle input for a day, or for every day, unless it is already saved
+    Fetch(FetchArgs),
+    /// Submit the answer to a part of a day, unless it is already known to be wrong
+    Submit(SubmitArgs),
+    /// Check that the alternative ways of solving a part agree with the day's own,
+    /// on puzzle inputs and on randomly generated ones
+    Diff(DiffArgs),
+    /// Generate a random puzzle input for a day
+    Gen(GenArgs),
+    /// Draw a part of a day being solved, as an animation in the terminal or as images
+    Vis(VisArgs),
+    /// Parse a puzzle input once, then query it with the day's own commands
+    Repl(ReplArgs),
+    /// Encrypt puzzle inputs so that they can be committed, or decrypt them again
+    #[command(subcommand)]
+    Vault(VaultCommand),
+    /// Re-run a day on its puzzle inputs whenever its source or puzzle inputs change
+    #[cfg(target_os = "linux")]
+    Watch(WatchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Part to run; both parts are run if not given
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input to run against, either a path (e.g. `./input.txt`), the name
+    /// of a file in the day's `res/` directory (or `$AOC_INPUT_DIR/day_NN/`), or
+    /// `-` for stdin; defaults to `input.txt`
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+    /// Run every available day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// How to print the answers; `json` and `csv` also include the input and timings
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Override a parameter of the day from the config file; may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param, conflicts_with = "all")]
+    params: Vec<(String, String)>,
+    /// Run this many days at once, each on its own thread, then print a summary of
+    /// every day (a day which panics does not stop the rest)
+    #[arg(long, requires = "all", value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: Option<u64>,
+    /// Count the allocations made by parsing and each part, then print the number
+    /// of allocations, bytes allocated and peak bytes in use for each; needs the
+    /// runner built with `--features alloc-profile`
+    #[arg(long, conflicts_with = "jobs")]
+    alloc: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark; every available day is benchmarked if not given
+    #[arg(long)]
+    day: Option<u8>,
+    /// Puzzle input to benchmark against, as for `run`; defaults to `input.txt`
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+    /// Number of times to run each stage
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// File to write the results to, as CSV with timings in nanoseconds
+    #[arg(long, default_value = "bench_output.txt")]
+    output: PathBuf,
+    /// Override a parameter of the day from the config file; may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param, requires = "day")]
+    params: Vec<(String, String)>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to fetch
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Fetch every available day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Where to download puzzle inputs from
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// File containing the session token; defaults to the `AOC_SESSION` environment variable
+    #[arg(long)]
+    session_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    #[arg(long)]
+    day: u8,
+    /// Part to submit
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Answer to submit; defaults to running the solution against `--input`
+    #[arg(long)]
+    answer: Option<String>,
+    /// Puzzle input to run the solution against, as for `run`; defaults to `input.txt`
+    #[arg(long, conflicts_with = "answer")]
+    input: Option<String>,
+    /// Override a parameter of the day from the config file; may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param, conflicts_with = "answer")]
+    params: Vec<(String, String)>,
+    /// Where to submit answers to
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// File containing the session token; defaults to the `AOC_SESSION` environment variable
+    #[arg(long)]
+    session_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    /// Day to check; every day with alternatives is checked if not given
+    #[arg(long)]
+    day: Option<u8>,
+    /// Puzzle input to check against, as for `run`; may be repeated. Defaults to
+    /// `test_input.txt` and `input.txt`, where they exist
+    #[arg(long = "input")]
+    inputs: Vec<String>,
+    /// Number of random puzzle inputs to check against
+    #[arg(long, default_value_t = 100)]
+    random: u64,
+    /// Seed of the first random puzzle input; each one after uses the next seed
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Size of the random puzzle inputs, which means something different for each day
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate a puzzle input for
+    #[arg(long)]
+    day: u8,
+    /// Size of the puzzle input, which means something different for each day
+    /// (e.g. the number of elves for day 1)
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+    /// Seed for the random numbers; the same seed and size always give the same puzzle input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// File to write the puzzle input to; printed to stdout if not given
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Override a parameter of the day from the config file (e.g. day 6's windows),
+    /// which the puzzle input must suit; may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+#[derive(Args)]
+struct VisArgs {
+    /// Day to draw
+    #[arg(long)]
+    day: u8,
+    /// Part to draw
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Puzzle input to draw, as for `run`; defaults to `input.txt`
+    #[arg(long)]
+    input: Option<String>,
+    /// Override a parameter of the day from the config file; may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// How to draw the frames; images must be written to a directory with `--output`
+    #[arg(long, value_enum, default_value_t = FrameFormat::Ascii)]
+    format: FrameFormat,
+    /// Directory to write the frames to, as `frame_00000.txt` and so on
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Only draw every Nth step (the final state is always drawn)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    every: u64,
+    /// Milliseconds to wait between frames in the terminal
+    #[arg(long, default_value_t = 50, conflicts_with = "output")]
+    delay: u64,
+    /// Width and height in pixels of each position in images
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..))]
+    scale: u64,
+}
+
+#[derive(Args)]
+struct ReplArgs {
+    /// Day to query
+    #[arg(long)]
+    day: u8,
+    /// Puzzle input to parse, as for `run` (but not stdin, which the commands are read
+    /// from); defaults to `input.txt`
+    #[arg(long)]
+    input: Option<String>,
+    /// Override a parameter of the day from the config file; may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+#[derive(Subcommand)]
+enum VaultCommand {
+    /// Print a new key, at random, to encrypt puzzle inputs with
+    Key,
+    /// Encrypt a puzzle input of a day (or of every day) as `<input>.enc`, removing the original
+    Encrypt {
+        #[command(flatten)]
+        files: VaultArgs,
+        /// Keep the original puzzle input as well
+        #[arg(long)]
+        keep: bool,
+    },
+    /// Decrypt an encrypted puzzle input of a day (or of every day), keeping the encrypted one
+    Decrypt {
+        #[command(flatten)]
+        files: VaultArgs,
+        /// Overwrite a puzzle input which is already there
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Args)]
+struct VaultArgs {
+    /// Day to encrypt/decrypt the puzzle input of
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Encrypt/decrypt the puzzle input of every available day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Name of the puzzle input, found in the day's own directories as for `run` (but never a
+    /// path, so that only the day's own puzzle inputs are changed)
+    #[arg(long, default_value = "input.txt")]
+    input: String,
+    /// File containing the key; defaults to `$AOC_VAULT_KEY`, or the file `$AOC_VAULT_KEY_FILE`
+    /// points at
+    #[arg(long)]
+    key_file: Option<PathBuf>,
+}
+
+#[cfg(target_os = "linux")]
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to watch
+    #[arg(long)]
+    day: u8,
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(name, value)| (name.trim().to_string(), value.to_string()))
+        .ok_or_else(|| format!("Expected NAME=VALUE, found {s:?}"))
+}
+
+// Set a day's parameters for a puzzle input: first from the config file, then from `--param`
+fn configure(
+    solution: &mut dyn DynSolution,
+    config: &Config,
+    input: &str,
+    params: &[(String, String)],
+) -> Result<(), String> {
+    config.configure_dyn(solution, input)?;
+    for (name, value) in params {
+        solution.set_param(name, value)?;
+    }
+    Ok(())
+}
+
+fn run_day(
+    solution: &dyn DynSolution,
+    parts: &[u8],
+    input: &Option<String>,
+) -> Result<Vec<Record>, input::Error> {
+    let name = input.as_deref().unwrap_or("input.txt");
+    let input = solution.parse_input(name)?;
+    let records = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solution.part(input.as_ref(), part);
+            let time = start.elapsed();
+            Record {
+                day: solution.day(),
+                part,
+                input: name.to_string(),
+                answer: answers::normalise(&answer),
+                time,
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(records)
+}
+
+// Run every day on `jobs` threads at once, printing a summary once they have all finished
+fn run_jobs(args: &RunArgs, jobs: usize, parts: &[u8], config: &Config) -> ExitCode {
+    let days = days::all()
+        .iter()
+        .map(|solution| solution.day())
+        .collect::<Vec<_>>();
+    let start = Instant::now();
+    // Each day is created on the thread which runs it, as solutions need not be `Send`
+    let runs = jobs::run_days(&days, jobs, |day| {
+        let mut solution = days::find(day).expect("Every day is available");
+        configure(solution.as_mut(), config, "input.txt", &[])?;
+        run_day(solution.as_ref(), parts, &None).map_err(|error| error.to_string())
+    });
+    let wall_time = start.elapsed();
+    let mut records = Vec::new();
+    for run in &runs {
+        match &run.status {
+            jobs::Status::Ok(day_records) => records.extend(day_records.iter().cloned()),
+            jobs::Status::Error(error) | jobs::Status::Panicked(error) => {
+                eprintln!("error: day {}: {}", run.day, error)
+            }
+        }
+    }
+    match args.format {
+        Format::Text => print!("{}", jobs::summary(&runs, wall_time)),
+        Format::Json => println!("{}", output::json(&records)),
+        Format::Csv => print!("{}", output::csv(&records)),
+    }
+    if runs
+        .iter()
+        .all(|run| matches!(run.status, jobs::Status::Ok(_)))
+    {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run(args: RunArgs, config: &Config) -> ExitCode {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    if args.alloc && !memory::ENABLED {
+        eprintln!("error: cannot count allocations without the `alloc-profile` feature (build with `--features alloc-profile`)");
+        return ExitCode::FAILURE;
+    }
+    if let Some(jobs) = args.jobs {
+        return run_jobs(&args, jobs as usize, &parts, config);
+    }
+    let solutions = if args.all {
+        days::all()
+    } else {
+        let number = args.day.expect("Either --day or --all is required");
+        match days::find(number) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("error: day {number} is not available (see `aoc list`)");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let name = args.input.as_deref().unwrap_or("input.txt");
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut records = Vec::new();
+    let mut profiles = Vec::new();
+    for mut solution in solutions {
+        if let Err(error) = configure(solution.as_mut(), config, name, &args.params) {
+            eprintln!("error: day {}: {}", solution.day(), error);
+            exit_code = ExitCode::FAILURE;
+            continue;
+        }
+        let day_records = if args.alloc {
+            memory::profile_day(solution.as_ref(), &parts, &args.input).map(
+                |(day_records, day_profiles)| {
+                    profiles.extend(day_profiles);
+                    day_records
+                },
+            )
+        } else {
+            run_day(solution.as_ref(), &parts, &args.input)
+        };
+        match day_records {
+            // Text is printed as we go, as some days take a while
+            Ok(day_records) if args.format == Format::Text => {
+                for record in day_records {
+                    println!("{}", output::text(&record));
+                }
+            }
+            Ok(day_records) => records.extend(day_records),
+            Err(error) => {
+                eprintln!("error: day {}: {}", solution.day(), error);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    match args.format {
+        Format::Text => {}
+        Format::Json => println!("{}", output::json(&records)),
+        Format::Csv => print!("{}", output::csv(&records)),
+    }
+    // Note: kept out of stdout for `json` and `csv`, so that they can still be read back
+    if args.alloc {
+        match args.format {
+            Format::Text => print!("\n{}", memory::table(&profiles)),
+            Format::Json | Format::Csv => eprint!("{}", memory::table(&profiles)),
+        }
+    }
+    exit_code
+}
+
+fn bench(args: BenchArgs, config: &Config) -> ExitCode {
+    let solutions = match args.day {
+        None => days::all(),
+        Some(number) => match days::find(number) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("error: day {number} is not available (see `aoc list`)");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    let name = args.input.as_deref().unwrap_or("input.txt");
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut timings = Vec::new();
+    for mut solution in solutions {
+        if let Err(error) = configure(solution.as_mut(), config, name, &args.params) {
+            eprintln!("error: day {}: {}", solution.day(), error);
+            exit_code = ExitCode::FAILURE;
+            continue;
+        }
+        match bench::bench_day(solution.as_ref(), name, args.iterations as usize) {
+            Ok(day_timings) => timings.extend(day_timings),
+            Err(error) => {
+                eprintln!("error: day {}: {}", solution.day(), error);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    bench::print(&timings);
+    if let Err(error) = bench::write(&args.output, &timings) {
+        eprintln!("error: cannot write {}: {}", args.output.display(), error);
+        return ExitCode::FAILURE;
+    }
+    exit_code
+}
+
+fn list() -> ExitCode {
+    for solution in days::all() {
+        println!("{:>2}  {}", solution.day(), days::name(solution.day()));
+    }
+    ExitCode::SUCCESS
+}
+
+fn new(args: NewArgs) -> ExitCode {
+    match new::new_day(&new::default_root(), args.day) {
+        Ok(dir) => {
+            println!("Created {}", dir.display());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(args: FetchArgs) -> ExitCode {
+    let solutions = if args.all {
+        days::all()
+    } else {
+        let number = args.day.expect("Either --day or --all is required");
+        match days::find(number) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("error: day {number} is not available (see `aoc list`)");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let session = match fetch::Client::session(args.session_file.as_deref()) {
+        Ok(session) => session,
+        Err(error) => {
+            eprintln!("error: cannot read the session token: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = fetch::Client::new(&args.base_url, session);
+    let mut exit_code = ExitCode::SUCCESS;
+    for solution in solutions {
+        match fetch::fetch(
+            &client,
+            solution.day(),
+            solution.res_dir(),
+            input::input_dir().as_deref(),
+        ) {
+            Ok(fetch::Fetched::Cached(path)) => println!("Already have {}", path.display()),
+            Ok(fetch::Fetched::Downloaded(path)) => println!("Downloaded {}", path.display()),
+            Err(error) => {
+                eprintln!("error: day {}: {}", solution.day(), error);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    exit_code
+}
+
+fn submit(args: SubmitArgs, config: &Config) -> ExitCode {
+    let Some(mut solution) = days::find(args.day) else {
+        eprintln!("error: day {} is not available (see `aoc list`)", args.day);
+        return ExitCode::FAILURE;
+    };
+    let name = args.input.as_deref().unwrap_or("input.txt");
+    if let Err(error) = configure(solution.as_mut(), config, name, &args.params) {
+        eprintln!("error: day {}: {}", args.day, error);
+        return ExitCode::FAILURE;
+    }
+    let answer = match args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => match run_day(solution.as_ref(), &[args.part], &args.input) {
+            Ok(records) => records[0].answer.clone(),
+            Err(error) => {
+                eprintln!("error: day {}: {}", args.day, error);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    if answer.is_empty() {
+        eprintln!("error: cannot submit an empty answer");
+        return ExitCode::FAILURE;
+    }
+    if answer.contains('\n') {
+        eprintln!("error: the answer spans multiple lines; read it and pass --answer instead:");
+        eprintln!("{answer}");
+        return ExitCode::FAILURE;
+    }
+
+    let dir = solution.save_dir();
+    match submit::Ledger::load(&dir) {
+        Ok(ledger) => match ledger.bounds(args.part) {
+            (None, None) => {}
+            (low, high) => println!(
+                "Part {} is known to be above {} and below {}",
+                args.part,
+                low.map_or("?".to_string(), |low| low.to_string()),
+                high.map_or("?".to_string(), |high| high.to_string())
+            ),
+        },
+        Err(error) => {
+            eprintln!("error: cannot read {}: {}", submit::LEDGER_NAME, error);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let session = match fetch::Client::session(args.session_file.as_deref()) {
+        Ok(session) => session,
+        Err(error) => {
+            eprintln!("error: cannot read the session token: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = fetch::Client::new(&args.base_url, session);
+    println!(
+        "Submitting {answer} for day {}, part {}",
+        args.day, args.part
+    );
+    match submit::submit(&client, args.day, args.part, &answer, &dir) {
+        Ok(submit::Outcome::Correct) => {
+            println!("{}", submit::Outcome::Correct);
+            ExitCode::SUCCESS
+        }
+        Ok(outcome) => {
+            println!("{outcome}");
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Print any disagreements, returning how many there were
+fn print_disagreements(day: u8, case: &str, comparisons: &[Comparison]) -> usize {
+    let disagreements = comparisons
+        .iter()
+        .filter(|comparison| !comparison.agrees())
+        .collect::<Vec<_>>();
+    for comparison in &disagreements {
+        println!("Day {day} {case}: {comparison}");
+    }
+    disagreements.len()
+}
+
+fn diff(args: DiffArgs, config: &Config) -> ExitCode {
+    let days = match args.day {
+        None => days::all()
+            .iter()
+            .filter(|solution| !solution.alternative_names().is_empty())
+            .map(|solution| solution.day())
+            .collect::<Vec<_>>(),
+        Some(number) => match days::find(number) {
+            Some(_) => vec![number],
+            None => {
+                eprintln!("error: day {number} is not available (see `aoc list`)");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    let mut exit_code = ExitCode::SUCCESS;
+    for day in days {
+        let solution = days::find(day).expect("Day is available");
+        if solution.alternative_names().is_empty() {
+            println!("Day {day} has no alternatives to check");
+            continue;
+        }
+        let inputs = if args.inputs.is_empty() {
+            ["test_input.txt", "input.txt"]
+                .into_iter()
+                .filter(|name| solution.resolve_input(name).is_ok())
+                .map(String::from)
+                .collect()
+        } else {
+            args.inputs.clone()
+        };
+
+        let (mut checked, mut disagreements) = (0, 0);
+        let mut fail = |error: String| {
+            eprintln!("error: day {day}: {error}");
+            exit_code = ExitCode::FAILURE;
+        };
+        for name in &inputs {
+            match diff::compare_input(day, config, name) {
+                Ok(comparisons) => {
+                    checked += 1;
+                    disagreements += print_disagreements(day, name, &comparisons);
+                }
+                Err(error) => fail(error),
+            }
+        }
+        for seed in args.seed..args.seed + args.random {
+            match diff::compare_generated(day, seed, args.size) {
+                Ok(Some((generated, comparisons))) => {
+                    checked += 1;
+                    let case = format!("seed {seed}");
+                    let found = print_disagreements(day, &case, &comparisons);
+                    if found > 0 {
+                        disagreements += found;
+                        let params = generated
+                            .params
+                            .iter()
+                            .map(|(name, value)| format!(" --param {name}={value}"))
+                            .collect::<String>();
+                        println!("Puzzle input (size {}){params}:", args.size);
+                        print!("{}", generated.input);
+                    }
+                }
+                Ok(None) => {
+                    println!("Day {day} cannot generate random puzzle inputs");
+                    break;
+                }
+                Err(error) => fail(format!("seed {seed}: {error}")),
+            }
+        }
+        println!(
+            "Day {day}: checked {} against {checked} puzzle inputs, {disagreements} disagreements",
+            solution
+                .alternative_names()
+                .iter()
+                .map(|(part, name)| format!("part {part} {name}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if disagreements > 0 {
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    exit_code
+}
+
+fn generate(args: GenArgs, config: &Config) -> ExitCode {
+    let Some(mut solution) = days::find(args.day) else {
+        eprintln!("error: day {} is not available (see `aoc list`)", args.day);
+        return ExitCode::FAILURE;
+    };
+    let name = args
+        .output
+        .as_ref()
+        .and_then(|output| output.file_name())
+        .and_then(|name| name.to_str())
+        .unwrap_or("input.txt")
+        .to_string();
+    if let Err(error) = configure(solution.as_mut(), config, &name, &args.params) {
+        eprintln!("error: day {}: {}", args.day, error);
+        return ExitCode::FAILURE;
+    }
+    let Some(generated) = solution.generate(&mut Rng::new(args.seed), args.size) else {
+        eprintln!(
+            "error: day {} cannot generate puzzle inputs (with these parameters)",
+            args.day
+        );
+        return ExitCode::FAILURE;
+    };
+    match &args.output {
+        Some(output) => {
+            if let Err(error) = std::fs::write(output, &generated.input) {
+                eprintln!("error: cannot write {}: {}", output.display(), error);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{}", generated.input),
+    }
+    if !generated.params.is_empty() {
+        let params = generated
+            .params
+            .iter()
+            .map(|(name, value)| format!(" --param {name}={value}"))
+            .collect::<String>();
+        eprintln!("note: solve this puzzle input with{params}");
+    }
+    ExitCode::SUCCESS
+}
+
+fn visualise(args: VisArgs, config: &Config) -> ExitCode {
+    let Some(mut solution) = days::find(args.day) else {
+        eprintln!("error: day {} is not available (see `aoc list`)", args.day);
+        return ExitCode::FAILURE;
+    };
+    let name = args.input.as_deref().unwrap_or("input.txt");
+    if let Err(error) = configure(solution.as_mut(), config, name, &args.params) {
+        eprintln!("error: day {}: {}", args.day, error);
+        return ExitCode::FAILURE;
+    }
+    let output = match (args.output, args.format) {
+        (None, FrameFormat::Ascii) => Output::Terminal {
+            delay: Duration::from_millis(args.delay),
+        },
+        (None, _) => {
+            eprintln!("error: images cannot be drawn in the terminal; write them with --output");
+            return ExitCode::FAILURE;
+        }
+        (Some(dir), format) => Output::Directory {
+            dir,
+            format,
+            scale: args.scale as usize,
+        },
+    };
+    let input = match solution.parse_input(name) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: day {}: {}", args.day, error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut writer = match vis::Writer::new(output) {
+        Ok(writer) => writer,
+        Err(error) => {
+            eprintln!("error: cannot create the output directory: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut sink = |frame| writer.write(frame);
+    let mut frames = Frames::new(args.every as usize, &mut sink);
+    if !solution.visualise(input.as_ref(), args.part, &mut frames) {
+        eprintln!(
+            "error: day {}, part {} has nothing to draw",
+            args.day, args.part
+        );
+        return ExitCode::FAILURE;
+    }
+    match writer.finish() {
+        Ok(count) => {
+            eprintln!("Drew {count} frames");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: cannot write the frames: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn query(args: ReplArgs, config: &Config) -> ExitCode {
+    let Some(mut solution) = days::find(args.day) else {
+        eprintln!("error: day {} is not available (see `aoc list`)", args.day);
+        return ExitCode::FAILURE;
+    };
+    let name = args.input.as_deref().unwrap_or("input.txt");
+    if name == input::STDIN {
+        eprintln!("error: the puzzle input cannot be read from stdin, as the commands are");
+        return ExitCode::FAILURE;
+    }
+    if let Err(error) = configure(solution.as_mut(), config, name, &args.params) {
+        eprintln!("error: day {}: {}", args.day, error);
+        return ExitCode::FAILURE;
+    }
+    let input = match solution.parse_input(name) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: day {}: {}", args.day, error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(mut day_repl) = solution.repl(input.as_ref()) else {
+        eprintln!("error: day {} has no commands to query it with", args.day);
+        return ExitCode::FAILURE;
+    };
+    // Only prompt someone typing, rather than a script
+    let stdin = io::stdin();
+    let prompt = stdin
+        .is_terminal()
+        .then(|| format!("day {:02}> ", args.day));
+    if prompt.is_some() {
+        eprintln!("Parsed {name}; `help` lists the commands");
+    }
+    match repl::run(
+        day_repl.as_mut(),
+        stdin.lock(),
+        io::stdout().lock(),
+        prompt.as_deref(),
+    ) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn vault(command: VaultCommand) -> ExitCode {
+    let (files, encrypt, force) = match command {
+        VaultCommand::Key => {
+            println!("{}", vault::Key::generate().to_hex());
+            return ExitCode::SUCCESS;
+        }
+        VaultCommand::Encrypt { files, keep } => (files, Some(keep), false),
+        VaultCommand::Decrypt { files, force } => (files, None, force),
+    };
+    if input::is_path(&files.input) {
+        eprintln!(
+            "error: expected the name of a puzzle input in the day's own directories, not a path: {}",
+            files.input
+        );
+        return ExitCode::FAILURE;
+    }
+    let key = match &files.key_file {
+        Some(path) => vault::Key::from_file(path).map(Some),
+        None => vault::Key::from_env(),
+    };
+    let key = match key {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            eprintln!(
+                "error: no key; set ${}, or ${} to a file holding it (`aoc vault key` makes one)",
+                vault::KEY_VAR,
+                vault::KEY_FILE_VAR
+            );
+            return ExitCode::FAILURE;
+        }
+        Err(error) => {
+            eprintln!("error: cannot read the key: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let solutions = if files.all {
+        days::all()
+    } else {
+        let number = files.day.expect("Either --day or --all is required");
+        match days::find(number) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("error: day {number} is not available (see `aoc list`)");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let mut exit_code = ExitCode::SUCCESS;
+    for solution in solutions {
+        let path = match solution.resolve_input(&files.input) {
+            Ok(path) => path,
+            // Not every day need have the puzzle input
+            Err(_) if files.all => continue,
+            Err(error) => {
+                eprintln!("error: day {}: {}", solution.day(), error);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let result = match (encrypt, vault::is_encrypted(&path)) {
+            (Some(_), true) => {
+                println!("Already encrypted {}", path.display());
+                continue;
+            }
+            (None, false) => {
+                println!("Already decrypted {}", path.display());
+                continue;
+            }
+            (Some(keep), false) => vault::encrypt_file(&path, &key).and_then(|encrypted| {
+                if !keep {
+                    std::fs::remove_file(&path)?;
+                }
+                println!("Encrypted {} as {}", path.display(), encrypted.display());
+                Ok(())
+            }),
+            (None, true) => vault::decrypt_file(&path, &key, force).map(|decrypted| {
+                println!("Decrypted {} as {}", path.display(), decrypted.display());
+            }),
+        };
+        if let Err(error) = result {
+            eprintln!("error: day {}: {}", solution.day(), error);
+            if error.kind() == std::io::ErrorKind::AlreadyExists {
+                eprintln!("note: `--force` overwrites it");
+            }
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    exit_code
+}
+
+#[cfg(target_os = "linux")]
+fn watch(args: WatchArgs, config: Option<&std::path::Path>) -> ExitCode {
+    let Some(solution) = days::find(args.day) else {
+        eprintln!("error: day {} is not available (see `aoc list`)", args.day);
+        return ExitCode::FAILURE;
+    };
+    let root = new::default_root();
+    let day_dir = root.join("days").join(days::name(args.day));
+    let res_dir = day_dir.join("res");
+    let mut dirs = vec![day_dir.join("src"), res_dir.clone()];
+    let save_dir = solution.save_dir();
+    if save_dir.is_dir() && !dirs.contains(&save_dir) {
+        dirs.push(save_dir);
+    }
+    let mut watcher = match watch::Watcher::new(&dirs) {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            eprintln!("error: cannot watch day {}: {}", args.day, error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let rerun = watch::Rerun {
+        root: &root,
+        solution: solution.as_ref(),
+        res_dir,
+        config,
+    };
+    // Puzzle inputs are looked for in the same directories as they are watched in
+    let input_dirs = &dirs[1..];
+    let mut changed = Vec::new();
+    loop {
+        if changed.is_empty() {
+            println!("==== Running day {}", args.day);
+        } else {
+            println!(
+                "==== {} changed, re-running day {}",
+                changed.join(", "),
+                args.day
+            );
+        }
+        if let Err(error) = rerun.once(input_dirs) {
+            eprintln!("error: cannot run day {}: {}", args.day, error);
+            return ExitCode::FAILURE;
+        }
+        match watcher.wait() {
+            Ok(paths) => {
+                changed = paths
+                    .iter()
+                    .map(|path| {
+                        path.strip_prefix(&root)
+                            .unwrap_or(path)
+                            .display()
+                            .to_string()
+                    })
+                    .collect();
+            }
+            Err(error) => {
+                eprintln!("error: cannot watch day {}: {}", args.day, error);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let config = match &cli.config {
+        Some(path) => Config::load(path),
+        None => Config::load_default(),
+    };
+    let config = match config {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match cli.command {
+        Command::Run(args) => run(args, &config),
+        Command::Bench(args) => bench(args, &config),
+        Command::List => list(),
+        Command::New(args) => new(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args, &config),
+        Command::Diff(args) => diff(args, &config),
+        Command::Gen(args) => generate(args, &config),
+        Command::Vis(args) => visualise(args, &config),
+        Command::Repl(args) => query(args, &config),
+        Command::Vault(command) => vault(command),
+        #[cfg(target_os = "linux")]
+        Command::Watch(args) => watch(args, cli.config.as_deref()),
+    }
+}
@@ -0,0 +1,242 @@
+use crate::bench::Stage;
+use crate::output::Record;
+use common::{answers, input, DynSolution};
+#[cfg(feature = "alloc-profile")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::time::Instant;
+
+// Counts the allocations made by a stage of a day (e.g. day 5 cloning a stack
+// on every move), for `run --alloc`
+//
+// A global allocator cannot be swapped in at runtime, so `Counting` is only the
+// runner's allocator when built with the `alloc-profile` feature, as it costs a
+// thread local lookup on every allocation, even while not counting. It only
+// counts while `measure` is running, and only on the thread calling `measure`.
+
+// Whether the runner was built to count allocations
+pub const ENABLED: bool = cfg!(feature = "alloc-profile");
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize, // Including reallocations
+    pub allocated: usize,   // Bytes, over every allocation
+    pub peak: usize,        // The most bytes in use at once, above what was in use beforehand
+}
+
+// Bytes in use can go below where they started, if memory allocated
+// beforehand is freed, so they are kept signed
+//
+// Note: only counted by `Counting`, so nothing reads them without it
+#[cfg_attr(not(feature = "alloc-profile"), allow(dead_code))]
+#[derive(Clone, Copy, Default)]
+struct Counts {
+    allocations: usize,
+    allocated: usize,
+    in_use: isize,
+    peak: isize,
+}
+
+thread_local! {
+    // Note: `const`, without a destructor, so that it can be used from within
+    // the allocator without allocating
+    static COUNTS: Cell<Option<Counts>> = const { Cell::new(None) };
+}
+
+// Count an allocation of `size` bytes, which changed the bytes in use by `change`
+#[cfg(feature = "alloc-profile")]
+fn count(size: usize, change: isize) {
+    let _ = COUNTS.try_with(|counts| {
+        if let Some(mut current) = counts.get() {
+            if size > 0 {
+                current.allocations += 1;
+                current.allocated += size;
+            }
+            current.in_use += change;
+            current.peak = current.peak.max(current.in_use);
+            counts.set(Some(current));
+        }
+    });
+}
+
+#[cfg(feature = "alloc-profile")]
+pub struct Counting;
+
+#[cfg(feature = "alloc-profile")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(layout.size(), layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(layout.size(), layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(0, -(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(new_size, new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+// Run `f`, counting the allocations it makes on this thread
+//
+// Note: only counts anything with `Counting` as the global allocator (see `ENABLED`)
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    COUNTS.with(|counts| counts.set(Some(Counts::default())));
+    let result = f();
+    let counts = COUNTS
+        .with(|counts| counts.take())
+        .expect("Counting since the start of `measure`");
+    let usage = Usage {
+        allocations: counts.allocations,
+        allocated: counts.allocated,
+        peak: counts.peak.max(0) as usize,
+    };
+    (result, usage)
+}
+
+// The allocations made by one stage of one day
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Profile {
+    pub day: u8,
+    pub stage: Stage,
+    pub usage: Usage,
+}
+
+// Run a day as `run` does, also counting the allocations made by parsing and
+// each part
+//
+// As for `bench`, the puzzle input is read in up front so that only parsing
+// is counted, and not IO. Each part's allocations include its answer.
+pub fn profile_day(
+    solution: &dyn DynSolution,
+    parts: &[u8],
+    input: &Option<String>,
+) -> Result<(Vec<Record>, Vec<Profile>), input::Error> {
+    let day = solution.day();
+    let name = input.as_deref().unwrap_or("input.txt");
+    let file = match name {
+        input::STDIN => "<stdin>".to_string(),
+        _ => solution.resolve_input(name)?.display().to_string(),
+    };
+    let text = input::read(solution.open_input(name)?)?;
+    let (parsed, usage) = measure(|| solution.parse(&text));
+    let parsed = parsed.map_err(|error| error.in_file(&file))?;
+    let mut profiles = vec![Profile {
+        day,
+        stage: Stage::Parse,
+        usage,
+    }];
+    let mut records = Vec::new();
+    for &part in parts {
+        let start = Instant::now();
+        let (answer, usage) = measure(|| solution.part(parsed.as_ref(), part));
+        let time = start.elapsed();
+        records.push(Record {
+            day,
+            part,
+            input: name.to_string(),
+            answer: answers::normalise(&answer),
+            time,
+        });
+        let stage = match part {
+            1 => Stage::Part1,
+            _ => Stage::Part2,
+        };
+        profiles.push(Profile { day, stage, usage });
+    }
+    Ok((records, profiles))
+}
+
+// Format a number of bytes to a sensible precision for a human to read
+pub fn human(bytes: usize) -> String {
+    match bytes {
+        0..=9_999 => format!("{bytes}B"),
+        10_000..=9_999_999 => format!("{:.1}KiB", bytes as f64 / 1024.0),
+        10_000_000..=9_999_999_999 => format!("{:.1}MiB", bytes as f64 / (1024.0 * 1024.0)),
+        _ => format!("{:.2}GiB", bytes as f64 / (1024.0 * 1024.0 * 1024.0)),
+    }
+}
+
+// A table of the allocations made by each stage of each day
+pub fn table(profiles: &[Profile]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<5}  {:>11}  {:>10}  {:>10}\n",
+        "Day", "Stage", "Allocations", "Allocated", "Peak"
+    );
+    for profile in profiles {
+        table.push_str(&format!(
+            "{:>3}  {:<5}  {:>11}  {:>10}  {:>10}\n",
+            profile.day,
+            profile.stage,
+            profile.usage.allocations,
+            human(profile.usage.allocated),
+            human(profile.usage.peak)
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc-profile")]
+    #[test]
+    fn count_allocations() {
+        let (_, usage) = measure(|| {
+            let mut v = Vec::<u8>::with_capacity(1000);
+            drop(vec![0u8; 3000]);
+            v.reserve_exact(2000);
+            v
+        });
+        assert_eq!(
+            usage,
+            Usage {
+                allocations: 3,
+                allocated: 1000 + 3000 + 2000,
+                peak: 4000,
+            }
+        );
+        assert_eq!(measure(|| 1 + 1), (2, Usage::default()));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(human(512), "512B");
+        assert_eq!(human(20 * 1024), "20.0KiB");
+        assert_eq!(human(3 * 1024 * 1024 / 2 * 10), "15.0MiB");
+        let profiles = [Profile {
+            day: 5,
+            stage: Stage::Part1,
+            usage: Usage {
+                allocations: 1503,
+                allocated: 48_000,
+                peak: 1200,
+            },
+        }];
+        assert_eq!(
+            table(&profiles),
+            concat!(
+                "Day  Stage  Allocations   Allocated        Peak\n",
+                "  5  part1         1503     46.9KiB       1200B\n",
+            )
+        );
+    }
+}
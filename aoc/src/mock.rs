@@ -0,0 +1,44 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+// A stand-in for Advent of Code, which answers a single request with a status
+// and body, and returns the request it was sent (the request line, headers and body)
+pub fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(&stream);
+        let mut request = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim_end().is_empty() {
+                break;
+            }
+            request.push(line.trim_end().to_string());
+        }
+        let length = request
+            .iter()
+            .filter_map(|header| {
+                header
+                    .to_lowercase()
+                    .strip_prefix("content-length: ")
+                    .map(str::to_string)
+            })
+            .find_map(|length| length.parse::<usize>().ok())
+            .unwrap_or(0);
+        let mut content = vec![0; length];
+        reader.read_exact(&mut content).unwrap();
+        request.push(String::from_utf8(content).unwrap());
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        request.join("\n")
+    });
+    (base_url, handle)
+}
@@ -0,0 +1,122 @@
+use crate::days;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The comment marking where `aoc new` registers a day, in both
+// `aoc/Cargo.toml` and `aoc/src/days.rs`
+pub const MARKER: &str = "New days are added above this line by `aoc new`";
+
+// The files of a new day, relative to its directory, and their templates
+//
+// The templates use `{{NN}}` for the day padded to 2 digits, and `{{N}}` for the day itself.
+const TEMPLATES: [(&str, &str); 4] = [
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml")),
+    ("src/lib.rs", include_str!("../templates/day/lib.rs")),
+    ("src/main.rs", include_str!("../templates/day/main.rs")),
+    ("res/test_input.txt", ""),
+];
+
+// The root of the repository this runner was built from
+pub fn default_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner is within the repository")
+        .to_path_buf()
+}
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{N}}", &day.to_string())
+}
+
+// Insert a line (indented the same as the marker) directly above the marker
+fn register(contents: &str, line: &str) -> Option<String> {
+    let marker = contents.find(MARKER)?;
+    let start = contents[..marker].rfind('\n').map_or(0, |index| index + 1);
+    let (before, after) = contents.split_at(start);
+    let indent = &after[..after.len() - after.trim_start().len()];
+    Some(format!("{before}{indent}{line}\n{after}"))
+}
+
+// Register a day in a file of the runner, by the line to add above the marker
+fn register_in(path: &Path, line: &str) -> io::Result<String> {
+    let contents = fs::read_to_string(path)?;
+    register(&contents, line).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Cannot find \"{MARKER}\" in {}", path.display()),
+        )
+    })
+}
+
+// Create a new day from the templates, and register it with the runner
+//
+// The workspace picks up every crate within `days/`, so only the runner
+// needs to be told about it. Nothing is written if the day already exists.
+pub fn new_day(root: &Path, day: u8) -> io::Result<PathBuf> {
+    let name = days::name(day);
+    let dir = root.join("days").join(&name);
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    // Check both registrations before writing anything, so a failure leaves nothing behind
+    let manifest = root.join("aoc").join("Cargo.toml");
+    let dispatch = root.join("aoc").join("src").join("days.rs");
+    let manifest_contents = register_in(
+        &manifest,
+        &format!("{name} = {{ path = \"../days/{name}\" }}"),
+    )?;
+    let dispatch_contents = register_in(&dispatch, &format!("Box::new({name}::Day{:02}),", day))?;
+
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().expect("Every file is within the day"))?;
+        fs::write(path, fill(template, day))?;
+    }
+    fs::write(manifest, manifest_contents)?;
+    fs::write(dispatch, dispatch_contents)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_above_marker() {
+        let contents = format!("vec![\n    Box::new(day_01::Day01),\n    // {MARKER}\n]\n");
+        assert_eq!(
+            register(&contents, "Box::new(day_02::Day02),").unwrap(),
+            format!(
+                "vec![\n    Box::new(day_01::Day01),\n    Box::new(day_02::Day02),\n    // {MARKER}\n]\n"
+            )
+        );
+        assert_eq!(register("vec![]", "Box::new(day_02::Day02),"), None);
+    }
+
+    #[test]
+    fn new_day_once() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), format!("# {MARKER}\n")).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), format!("// {MARKER}\n")).unwrap();
+
+        let dir = new_day(&root, 16).unwrap();
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day16;") && lib.contains("const DAY: u8 = 16;"));
+        assert!(dir.join("res/test_input.txt").is_file());
+        let dispatch = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert_eq!(dispatch, format!("Box::new(day_16::Day16),\n// {MARKER}\n"));
+
+        // A day is never overwritten
+        let error = new_day(&root, 16).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,219 @@
+use clap::ValueEnum;
+use std::time::Duration;
+
+// How answers are printed by `aoc run`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable, one answer per line
+    #[default]
+    Text,
+    /// A JSON array of answer objects
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+// An answer to one part of a day, as reported by `aoc run`
+//
+// The fields (and their order) form the schema of the JSON and CSV output,
+// so should only ever be added to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+    pub time: Duration, // Time taken to solve the part, excluding parsing
+}
+
+// Print a single answer as text, with multi-line answers (e.g. day 10's
+// image) starting on their own line
+pub fn text(record: &Record) -> String {
+    if record.answer.contains('\n') {
+        format!(
+            "Day {:02}, part {}:\n{}",
+            record.day, record.part, record.answer
+        )
+    } else {
+        format!(
+            "Day {:02}, part {}: {}",
+            record.day, record.part, record.answer
+        )
+    }
+}
+
+// Escape a string as a JSON string literal
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+pub fn json(records: &[Record]) -> String {
+    let objects = records
+        .iter()
+        .map(|record| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"input\": {}, \"answer\": {}, \"time_ns\": {}}}",
+                record.day,
+                record.part,
+                json_string(&record.input),
+                json_string(&record.answer),
+                record.time.as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+    if objects.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", objects.join(",\n"))
+    }
+}
+
+// Quote a CSV field if it needs it, doubling any quotes within it
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn csv(records: &[Record]) -> String {
+    let mut csv = String::from("day,part,input,answer,time_ns\n");
+    for record in records {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            record.day,
+            record.part,
+            csv_field(&record.input),
+            csv_field(&record.answer),
+            record.time.as_nanos()
+        ));
+    }
+    csv
+}
+
+// Split CSV into its rows of fields, undoing `csv_field`'s quoting
+fn csv_rows(s: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let (mut row, mut field) = (Vec::new(), String::new());
+    let (mut quoted, mut chars) = (false, s.chars().peekable());
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+// Read back the answers written by `csv`, e.g. from another run of `aoc run --format csv`
+pub fn read_csv(s: &str) -> Result<Vec<Record>, String> {
+    let mut rows = csv_rows(s).into_iter();
+    match rows.next() {
+        Some(header) if header.join(",") == "day,part,input,answer,time_ns" => {}
+        header => return Err(format!("Expected a header of answers, found {header:?}")),
+    }
+    rows.map(|row| match &row[..] {
+        [day, part, input, answer, time_ns] => Ok(Record {
+            day: day
+                .parse()
+                .map_err(|_| format!("Cannot parse day {day:?}"))?,
+            part: part
+                .parse()
+                .map_err(|_| format!("Cannot parse part {part:?}"))?,
+            input: input.clone(),
+            answer: answer.clone(),
+            time: Duration::from_nanos(
+                time_ns
+                    .parse()
+                    .map_err(|_| format!("Cannot parse time {time_ns:?}"))?,
+            ),
+        }),
+        _ => Err(format!("Expected 5 fields, found {row:?}")),
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 5,
+                part: 1,
+                input: "input.txt".to_string(),
+                answer: "CMZ".to_string(),
+                time: Duration::from_nanos(1500),
+            },
+            Record {
+                day: 10,
+                part: 2,
+                input: "my \"input\".txt".to_string(),
+                answer: "#.\n.#".to_string(),
+                time: Duration::from_nanos(20),
+            },
+        ]
+    }
+
+    #[test]
+    fn format_json() {
+        assert_eq!(json(&[]), "[]");
+        assert_eq!(
+            json(&records()),
+            concat!(
+                "[\n",
+                "  {\"day\": 5, \"part\": 1, \"input\": \"input.txt\", \"answer\": \"CMZ\", \"time_ns\": 1500},\n",
+                "  {\"day\": 10, \"part\": 2, \"input\": \"my \\\"input\\\".txt\", \"answer\": \"#.\\n.#\", \"time_ns\": 20}\n",
+                "]"
+            )
+        );
+    }
+
+    #[test]
+    fn format_csv() {
+        assert_eq!(
+            csv(&records()),
+            concat!(
+                "day,part,input,answer,time_ns\n",
+                "5,1,input.txt,CMZ,1500\n",
+                "10,2,\"my \"\"input\"\".txt\",\"#.\n.#\",20\n",
+            )
+        );
+    }
+
+    #[test]
+    fn read_back_csv() {
+        assert_eq!(read_csv(&csv(&records())), Ok(records()));
+        assert_eq!(read_csv("day,part,input,answer,time_ns\n"), Ok(vec![]));
+        assert!(read_csv("Day 05, part 1: CMZ\n").is_err());
+        assert!(read_csv("day,part,input,answer,time_ns\n5,1,input.txt\n").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+use common::repl::Repl;
+use std::io::{self, BufRead, Write};
+
+// Commands every REPL has, on top of the day's own
+const BUILT_IN: [(&str, &str); 2] = [
+    ("help", "List the commands"),
+    ("quit", "Leave the REPL (as does the end of input)"),
+];
+
+fn help(repl: &dyn Repl) -> String {
+    let commands = repl
+        .commands()
+        .into_iter()
+        .chain(BUILT_IN)
+        .collect::<Vec<_>>();
+    let width = commands
+        .iter()
+        .map(|(usage, _)| usage.len())
+        .max()
+        .unwrap_or(0);
+    commands
+        .iter()
+        .map(|(usage, about)| format!("  {usage:<width$}  {about}\n"))
+        .collect()
+}
+
+// Read commands a line at a time, running each against the day's REPL until
+// `quit` or the end of input; `prompt` is shown before each command (if any)
+pub fn run(
+    repl: &mut dyn Repl,
+    input: impl BufRead,
+    mut output: impl Write,
+    prompt: Option<&str>,
+) -> io::Result<()> {
+    let mut lines = input.lines();
+    loop {
+        if let Some(prompt) = prompt {
+            write!(output, "{prompt}")?;
+            output.flush()?;
+        }
+        let Some(line) = lines.next().transpose()? else {
+            return Ok(());
+        };
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let Some((&command, args)) = words.split_first() else {
+            continue;
+        };
+        match command {
+            "help" => write!(output, "{}", help(repl))?,
+            "quit" | "exit" => return Ok(()),
+            _ => match repl.run(command, args) {
+                Ok(text) if text.is_empty() || text.ends_with('\n') => write!(output, "{text}")?,
+                Ok(text) => writeln!(output, "{text}")?,
+                Err(error) => writeln!(output, "error: {error}")?,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts up, to try the built in commands alongside a day's own
+    struct Counter {
+        count: i32,
+    }
+
+    impl Repl for Counter {
+        fn commands(&self) -> Vec<(&'static str, &'static str)> {
+            vec![("add [n]", "Add n (or 1) to the count")]
+        }
+
+        fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+            match command {
+                "add" => {
+                    self.count += common::repl::arg_or(args, 0, "n", 1)?;
+                    Ok(self.count.to_string())
+                }
+                _ => Err(common::repl::unknown(command)),
+            }
+        }
+    }
+
+    #[test]
+    fn commands() {
+        let mut counter = Counter { count: 0 };
+        let input = "add\n\nadd 41\nadd x\nsub 1\nhelp\nquit\nadd\n";
+        let mut output = Vec::new();
+        run(&mut counter, input.as_bytes(), &mut output, Some("> ")).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            concat!(
+                "> 1\n",
+                "> > 42\n",
+                "> error: Cannot parse <n> from \"x\"\n",
+                "> error: Unknown command \"sub\" (see `help`)\n",
+                ">   add [n]  Add n (or 1) to the count\n",
+                "  help     List the commands\n",
+                "  quit     Leave the REPL (as does the end of input)\n",
+                "> ",
+            )
+        );
+        assert_eq!(counter.count, 42);
+    }
+}
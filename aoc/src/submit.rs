@@ -0,0 +1,339 @@
+use crate::fetch::Client;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// The name of the ledger of submitted answers, saved alongside the puzzle input
+pub const LEDGER_NAME: &str = "submissions.txt";
+
+// What Advent of Code made of a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // Submitted too soon after a wrong answer, with how long there is left to wait
+    RateLimited(String),
+    // Submitted to a part which is already solved (or not yet unlocked)
+    WrongLevel,
+    // Anything else, with the text of the response
+    Unknown(String),
+}
+
+impl Outcome {
+    // Only definite verdicts on an answer are worth remembering
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::RateLimited(_) => "rate-limited",
+            Outcome::WrongLevel => "wrong-level",
+            Outcome::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "wrong" => Some(Outcome::Wrong),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Wrong => write!(f, "That's not the right answer"),
+            Outcome::TooHigh => write!(f, "That's not the right answer; it's too high"),
+            Outcome::TooLow => write!(f, "That's not the right answer; it's too low"),
+            Outcome::RateLimited(wait) => {
+                write!(f, "You gave an answer too recently; {wait} left to wait")
+            }
+            Outcome::WrongLevel => write!(f, "That part is already solved, or not yet unlocked"),
+            Outcome::Unknown(text) => write!(f, "Cannot tell whether that was right: {text}"),
+        }
+    }
+}
+
+// Strip the HTML tags from the `<article>` of a response, which holds its message
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(_, rest)| rest.split("</article>").next().unwrap_or(rest))
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Work out the outcome from the page Advent of Code responds with
+pub fn parse_response(html: &str) -> Outcome {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map_or("some time", |(wait, _)| wait);
+        Outcome::RateLimited(wait.to_string())
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+// Every answer submitted for a day, and what came of it
+//
+// Each line is of the form:
+//
+// <part> <outcome> <answer>
+//
+// e.g.
+//
+// 1 too-high 70613
+//
+#[derive(Debug, Default)]
+pub struct Ledger {
+    pub submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let submissions = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(index, line)| {
+                let mut words = line.trim().splitn(3, ' ');
+                match (
+                    words.next(),
+                    words.next().and_then(Outcome::from_name),
+                    words.next(),
+                ) {
+                    (Some(part @ ("1" | "2")), Some(outcome), Some(answer)) => Ok(Submission {
+                        part: part.parse().expect("Part is either 1 or 2"),
+                        outcome,
+                        answer: answer.to_string(),
+                    }),
+                    _ => Err(format!(
+                        "Line {}: expected `<part> <outcome> <answer>`, found {line:?}",
+                        index + 1
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Ledger { submissions })
+    }
+
+    // Load the ledger from a directory, or an empty ledger if there is none yet
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let path = dir.join(LEDGER_NAME);
+        if !path.is_file() {
+            return Ok(Ledger::default());
+        }
+        Ledger::parse(&fs::read_to_string(&path)?)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        let mut contents = String::from(
+            "# Answers submitted to Advent of Code, written by `aoc submit`\n# <part> <outcome> <answer>\n",
+        );
+        for submission in &self.submissions {
+            contents.push_str(&format!(
+                "{} {} {}\n",
+                submission.part,
+                submission.outcome.name(),
+                submission.answer
+            ));
+        }
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(LEDGER_NAME), contents)
+    }
+
+    fn part(&self, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.part == part)
+    }
+
+    // The tightest bounds on the answer to a part, from answers which were too low/high
+    pub fn bounds(&self, part: u8) -> (Option<i128>, Option<i128>) {
+        let bound = |outcome: Outcome| {
+            self.part(part)
+                .filter(move |submission| submission.outcome == outcome)
+                .filter_map(|submission| submission.answer.parse::<i128>().ok())
+        };
+        (bound(Outcome::TooLow).max(), bound(Outcome::TooHigh).min())
+    }
+
+    // Check whether an answer is worth submitting, given everything submitted so far
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        if let Some(correct) = self.part(part).find(|s| s.outcome == Outcome::Correct) {
+            return Err(format!(
+                "Part {part} is already solved, with {}",
+                correct.answer
+            ));
+        }
+        if let Some(previous) = self.part(part).find(|s| s.answer == answer) {
+            return Err(format!(
+                "{answer} was already submitted for part {part}: {}",
+                previous.outcome
+            ));
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let (low, high) = self.bounds(part);
+            if let Some(low) = low.filter(|&low| value <= low) {
+                return Err(format!("{answer} is too low, as {low} was already too low"));
+            }
+            if let Some(high) = high.filter(|&high| value >= high) {
+                return Err(format!(
+                    "{answer} is too high, as {high} was already too high"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, outcome: Outcome) {
+        self.submissions.push(Submission {
+            part,
+            outcome,
+            answer: answer.to_string(),
+        });
+    }
+}
+
+// Submit an answer, unless the ledger already rules it out, and record the outcome
+pub fn submit(client: &Client, day: u8, part: u8, answer: &str, dir: &Path) -> io::Result<Outcome> {
+    let mut ledger = Ledger::load(dir)?;
+    ledger
+        .check(part, answer)
+        .map_err(|error| io::Error::new(io::ErrorKind::AlreadyExists, error))?;
+    let outcome = parse_response(&client.post_answer(day, part, answer)?);
+    if outcome.is_final() {
+        ledger.record(part, answer, outcome.clone());
+        ledger.save(dir)?;
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::serve_once;
+    use std::env;
+    use std::path::PathBuf;
+
+    const TOO_HIGH: &str = "<html><main><article><p>That's not the right answer; \
+        your answer is too high.  If you're stuck, ... <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article></main></html>";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-submit-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn parse_responses() {
+        assert_eq!(parse_response(TOO_HIGH), Outcome::TooHigh);
+        assert_eq!(
+            parse_response(
+                "<article><p>Please don't repeatedly request this endpoint.</p></article>"
+            ),
+            Outcome::Unknown("Please don't repeatedly request this endpoint.".to_string())
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>That's the right answer! You are one gold star closer.</p></article>"
+            ),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait.</p></article>"),
+            Outcome::RateLimited("4m 12s".to_string())
+        );
+        assert_eq!(
+            parse_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Outcome::WrongLevel
+        );
+    }
+
+    #[test]
+    fn ledger_bounds() {
+        let ledger = Ledger::parse(
+            "1 too-low 100\n1 too-high 500\n1 too-high 300\n1 wrong abc\n2 correct 7\n",
+        )
+        .unwrap();
+        assert_eq!(ledger.bounds(1), (Some(100), Some(300)));
+        assert!(ledger.check(1, "200").is_ok());
+        assert!(ledger.check(1, "100").is_err());
+        assert!(ledger.check(1, "300").is_err());
+        assert!(ledger.check(1, "abc").is_err());
+        assert!(ledger.check(2, "8").is_err());
+        assert!(Ledger::parse("3 correct 7").is_err());
+    }
+
+    #[test]
+    fn submit_and_record() {
+        let (base_url, server) = serve_once("200 OK", TOO_HIGH);
+        let client = Client::new(&base_url, Some("abc123".to_string()));
+        let dir = temp_dir("record");
+
+        assert_eq!(
+            submit(&client, 1, 2, "500", &dir).unwrap(),
+            Outcome::TooHigh
+        );
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=500"));
+        let ledger = Ledger::load(&dir).unwrap();
+        assert_eq!(ledger.bounds(2), (None, Some(500)));
+
+        // The server has gone, so this must be refused without a request
+        let error = submit(&client, 1, 2, "600", &dir).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use clap::ValueEnum;
+use common::visualise::{self, Frame};
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+// How frames are drawn by `aoc vis`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum FrameFormat {
+    /// Text, animated in the terminal (or written as `.txt` files with --output)
+    #[default]
+    Ascii,
+    /// Colour images, written as `.ppm` files
+    Ppm,
+    /// Greyscale images, written as `.pgm` files
+    Pgm,
+}
+
+impl FrameFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            FrameFormat::Ascii => "txt",
+            FrameFormat::Ppm => "ppm",
+            FrameFormat::Pgm => "pgm",
+        }
+    }
+
+    fn encode(self, frame: &Frame, scale: usize) -> Vec<u8> {
+        match self {
+            FrameFormat::Ascii => visualise::ascii(frame).into_bytes(),
+            FrameFormat::Ppm => visualise::ppm(frame, scale),
+            FrameFormat::Pgm => visualise::pgm(frame, scale),
+        }
+    }
+}
+
+// Where the frames of a visualisation end up
+pub enum Output {
+    // Animated in the terminal, waiting between each frame
+    Terminal {
+        delay: Duration,
+    },
+    // Written to a directory as a numbered sequence of files, e.g. `frame_00000.ppm`
+    Directory {
+        dir: PathBuf,
+        format: FrameFormat,
+        scale: usize,
+    },
+}
+
+// Writes frames as they are drawn, keeping hold of the first error (as days
+// cannot be stopped part way through drawing)
+pub struct Writer {
+    output: Output,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+impl Writer {
+    pub fn new(output: Output) -> io::Result<Self> {
+        if let Output::Directory { dir, .. } = &output {
+            fs::create_dir_all(dir)?;
+        }
+        Ok(Writer {
+            output,
+            frames: 0,
+            error: None,
+        })
+    }
+
+    pub fn write(&mut self, frame: Frame) {
+        if self.error.is_none() {
+            self.error = self.try_write(&frame).err();
+        }
+        self.frames += 1;
+    }
+
+    fn try_write(&self, frame: &Frame) -> io::Result<()> {
+        match &self.output {
+            Output::Terminal { delay } => {
+                let mut stdout = io::stdout().lock();
+                // Each frame replaces the last in a terminal, otherwise they
+                // are separated by a blank line
+                if stdout.is_terminal() {
+                    write!(stdout, "\x1b[2J\x1b[H")?;
+                } else if self.frames > 0 {
+                    writeln!(stdout)?;
+                }
+                write!(stdout, "{}", visualise::ascii(frame))?;
+                stdout.flush()?;
+                thread::sleep(*delay);
+                Ok(())
+            }
+            Output::Directory { dir, format, scale } => fs::write(
+                frame_path(dir, self.frames, *format),
+                format.encode(frame, *scale),
+            ),
+        }
+    }
+
+    // The number of frames written, or the first error writing them
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.frames),
+        }
+    }
+}
+
+fn frame_path(dir: &Path, index: usize, format: FrameFormat) -> PathBuf {
+    dir.join(format!("frame_{index:05}.{}", format.extension()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::visualise::Pixel;
+    use common::Grid;
+    use std::env;
+
+    #[test]
+    fn write_directory() {
+        let dir = env::temp_dir().join(format!("aoc-vis-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let output = Output::Directory {
+            dir: dir.clone(),
+            format: FrameFormat::Pgm,
+            scale: 3,
+        };
+        let mut writer = Writer::new(output).unwrap();
+        for width in [1, 2] {
+            writer.write(Grid::new(width, 1, Pixel::new('#', [255; 3])));
+        }
+        assert_eq!(writer.finish().unwrap(), 2);
+        let image = fs::read(frame_path(&dir, 1, FrameFormat::Pgm)).unwrap();
+        assert!(image.starts_with(b"P5\n6 3\n255\n"));
+        assert_eq!(image.len(), 11 + 6 * 3);
+        assert!(!frame_path(&dir, 2, FrameFormat::Pgm).exists());
+        fs::remove_dir_all(&dir).unwrap();
+
+        // The directory has gone, so the frames cannot be written
+        let output = Output::Directory {
+            dir: dir.join("frames"),
+            format: FrameFormat::Ascii,
+            scale: 1,
+        };
+        let mut writer = Writer::new(output).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        writer.write(Grid::new(1, 1, Pixel::new('#', [255; 3])));
+        assert!(writer.finish().is_err());
+    }
+}
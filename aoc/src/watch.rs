@@ -0,0 +1,357 @@
+use crate::output::{self, Record};
+use common::answers::{self, Answer};
+use common::DynSolution;
+use inotify::{Inotify, WatchDescriptor, WatchMask};
+use std::collections::BTreeSet;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+// How long to wait for things to settle after a change, as saving a file is
+// often several changes in a row (e.g. editors which write a temporary file,
+// then rename it over the original)
+const SETTLE: Duration = Duration::from_millis(100);
+
+// Editors keep temporary files next to the ones being edited (e.g.
+// `.lib.rs.swp` or `lib.rs~`), which are not worth re-running a day for
+pub fn is_relevant(name: &OsStr) -> bool {
+    let name = name.to_string_lossy();
+    !(name.starts_with('.') || name.ends_with('~') || name.ends_with(".swp"))
+}
+
+// Watches directories (but not their subdirectories) for files changing, using inotify
+pub struct Watcher {
+    inotify: Inotify,
+    dirs: Vec<(WatchDescriptor, PathBuf)>,
+    buffer: Vec<u8>,
+}
+
+impl Watcher {
+    pub fn new(dirs: &[PathBuf]) -> io::Result<Self> {
+        let inotify = Inotify::init()?;
+        let mask = WatchMask::CLOSE_WRITE
+            | WatchMask::CREATE
+            | WatchMask::DELETE
+            | WatchMask::MOVED_FROM
+            | WatchMask::MOVED_TO;
+        let dirs = dirs
+            .iter()
+            .map(|dir| Ok((inotify.watches().add(dir, mask)?, dir.clone())))
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Watcher {
+            inotify,
+            dirs,
+            buffer: vec![0; 4096],
+        })
+    }
+
+    // The relevant files changed by a batch of events
+    fn changes(&mut self, block: bool) -> io::Result<BTreeSet<PathBuf>> {
+        let events = if block {
+            self.inotify.read_events_blocking(&mut self.buffer)
+        } else {
+            self.inotify.read_events(&mut self.buffer)
+        };
+        let events = match events {
+            Ok(events) => events,
+            Err(error) if error.kind() == io::ErrorKind::WouldBlock => return Ok(BTreeSet::new()),
+            Err(error) => return Err(error),
+        };
+        Ok(events
+            .filter_map(|event| {
+                let name = event.name.filter(|name| is_relevant(name))?;
+                let (_, dir) = self.dirs.iter().find(|(wd, _)| *wd == event.wd)?;
+                Some(dir.join(name))
+            })
+            .collect())
+    }
+
+    // Wait until a relevant file changes, then for things to settle, returning
+    // every file which changed
+    pub fn wait(&mut self) -> io::Result<BTreeSet<PathBuf>> {
+        let mut changed = BTreeSet::new();
+        while changed.is_empty() {
+            changed = self.changes(true)?;
+        }
+        loop {
+            thread::sleep(SETTLE);
+            let more = self.changes(false)?;
+            if more.is_empty() {
+                return Ok(changed);
+            }
+            changed.extend(more);
+        }
+    }
+}
+
+// Whether a puzzle input is one of the examples (e.g. `test_input.txt`) rather
+// than a real puzzle input
+fn is_example(name: &str) -> bool {
+    name.starts_with("test")
+}
+
+// Put puzzle inputs in the order they are run: the examples first (as they are
+// quick, and a day which gets them wrong is not worth running on the real
+// puzzle input), then the rest
+pub fn order(names: impl IntoIterator<Item = String>) -> Vec<String> {
+    let names = names.into_iter().collect::<BTreeSet<_>>();
+    let (mut ordered, rest): (Vec<_>, Vec<_>) =
+        names.into_iter().partition(|name| is_example(name));
+    ordered.extend(rest);
+    ordered
+}
+
+// The puzzle inputs to re-run a day on: those with known answers, any other
+// examples in the given directories, and `input.txt`; as long as they exist
+fn inputs(solution: &dyn DynSolution, dirs: &[PathBuf], answers: &[Answer]) -> Vec<String> {
+    let examples = dirs
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| is_example(name) && name.ends_with(".txt"));
+    let names = answers
+        .iter()
+        .map(|answer| answer.input.clone())
+        .chain(examples)
+        .chain(["input.txt".to_string()])
+        .filter(|name| solution.resolve_input(name).is_ok());
+    order(names)
+}
+
+// How an answer compares to the known answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+pub fn outcome(record: &Record, answers: &[Answer]) -> Outcome {
+    let known = answers
+        .iter()
+        .find(|answer| answer.input == record.input && answer.part == record.part);
+    match known {
+        None => Outcome::Unknown,
+        Some(known) if answers::normalise(&known.answer) == answers::normalise(&record.answer) => {
+            Outcome::Pass
+        }
+        Some(known) => Outcome::Fail {
+            expected: answers::normalise(&known.answer),
+        },
+    }
+}
+
+// A line (or, for multi-line answers, a block) describing how an answer compares
+pub fn report(record: &Record, outcome: &Outcome) -> String {
+    let name = format!("{} part {}", record.input, record.part);
+    let multi_line = record.answer.contains('\n');
+    match outcome {
+        Outcome::Pass if multi_line => format!("PASS {name}:\n{}", record.answer),
+        Outcome::Pass => format!("PASS {name}: {}", record.answer),
+        Outcome::Unknown if multi_line => {
+            format!("???? {name} (no known answer):\n{}", record.answer)
+        }
+        Outcome::Unknown => format!("???? {name}: {} (no known answer)", record.answer),
+        Outcome::Fail { expected } if multi_line || expected.contains('\n') => format!(
+            "FAIL {name}, got:\n{}\nexpected:\n{expected}",
+            record.answer
+        ),
+        Outcome::Fail { expected } => {
+            format!("FAIL {name}: got {}, expected {expected}", record.answer)
+        }
+    }
+}
+
+// Re-runs a day by building the runner afresh, then running it as a separate
+// process, so that it picks up changes to the day's code
+pub struct Rerun<'a> {
+    pub root: &'a Path,
+    pub solution: &'a dyn DynSolution,
+    pub res_dir: PathBuf,
+    pub config: Option<&'a Path>,
+}
+
+impl Rerun<'_> {
+    fn cargo(&self) -> Command {
+        let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+        command.current_dir(self.root);
+        command
+    }
+
+    // Build the runner, or give back the errors (in short) from building it
+    fn build(&self) -> io::Result<Result<(), String>> {
+        let output = self
+            .cargo()
+            .args([
+                "build",
+                "--release",
+                "-q",
+                "-p",
+                "aoc",
+                "--message-format",
+                "short",
+            ])
+            .output()?;
+        if output.status.success() {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(String::from_utf8_lossy(&output.stderr)
+                .trim_end()
+                .to_string()))
+        }
+    }
+
+    // Run both parts on a puzzle input, or give back why they could not be
+    fn run(&self, input: &str) -> io::Result<Result<Vec<Record>, String>> {
+        let mut command = self.cargo();
+        command.args(["run", "--release", "-q", "-p", "aoc", "--"]);
+        if let Some(config) = self.config {
+            command.arg("--config").arg(config);
+        }
+        let day = self.solution.day().to_string();
+        let output = command
+            .args(["run", "--day", &day, "--input", input, "--format", "csv"])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            // The last line is the error, or where a day panicked
+            let error = stderr.lines().rfind(|line| !line.trim().is_empty());
+            return Ok(Err(error.unwrap_or("failed").trim().to_string()));
+        }
+        Ok(output::read_csv(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    // Build and run the day on each of its puzzle inputs, printing how its
+    // answers compare; false if anything failed
+    pub fn once(&self, dirs: &[PathBuf]) -> io::Result<bool> {
+        if let Err(errors) = self.build()? {
+            println!("Cannot build day {}:\n{errors}", self.solution.day());
+            return Ok(false);
+        }
+        let answers = match answers::load(&self.res_dir.to_string_lossy()) {
+            Ok(answers) => answers.unwrap_or_default(),
+            Err(error) => {
+                println!("Cannot load {}: {error}", answers::MANIFEST);
+                Vec::new()
+            }
+        };
+        let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+        for input in inputs(self.solution, dirs, &answers) {
+            if failed > 0 && !is_example(&input) {
+                println!("Skipping {input}, as an example failed");
+                continue;
+            }
+            let records = match self.run(&input)? {
+                Ok(records) => records,
+                Err(error) => {
+                    println!("FAIL {input}: {error}");
+                    failed += 1;
+                    continue;
+                }
+            };
+            for record in records {
+                let outcome = outcome(&record, &answers);
+                match outcome {
+                    Outcome::Pass => passed += 1,
+                    Outcome::Fail { .. } => failed += 1,
+                    Outcome::Unknown => unknown += 1,
+                }
+                println!("{}", report(&record, &outcome));
+            }
+        }
+        println!("{passed} passed, {failed} failed, {unknown} without a known answer");
+        Ok(failed == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(input: &str, part: u8, answer: &str) -> Record {
+        Record {
+            day: 10,
+            part,
+            input: input.to_string(),
+            answer: answer.to_string(),
+            time: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn relevant() {
+        assert!(is_relevant(OsStr::new("lib.rs")));
+        assert!(is_relevant(OsStr::new("answers.txt")));
+        assert!(!is_relevant(OsStr::new(".lib.rs.swp")));
+        assert!(!is_relevant(OsStr::new("lib.rs~")));
+        assert!(!is_relevant(OsStr::new("4913.swp")));
+    }
+
+    #[test]
+    fn input_order() {
+        let names = [
+            "input.txt",
+            "test_input_1.txt",
+            "big.txt",
+            "test_input_0.txt",
+            "input.txt",
+        ];
+        assert_eq!(
+            order(names.map(String::from)),
+            [
+                "test_input_0.txt",
+                "test_input_1.txt",
+                "big.txt",
+                "input.txt"
+            ]
+        );
+    }
+
+    #[test]
+    fn compare() {
+        let answers =
+            common::answers::parse("test_input.txt 1 13140\ntest_input.txt 2 #.\\n.#\n").unwrap();
+        let pass = record("test_input.txt", 1, "13140");
+        assert_eq!(outcome(&pass, &answers), Outcome::Pass);
+        assert_eq!(
+            report(&pass, &Outcome::Pass),
+            "PASS test_input.txt part 1: 13140"
+        );
+
+        let fail = record("test_input.txt", 2, "#.\n##");
+        let expected = outcome(&fail, &answers);
+        assert_eq!(
+            report(&fail, &expected),
+            "FAIL test_input.txt part 2, got:\n#.\n##\nexpected:\n#.\n.#"
+        );
+
+        let unknown = record("input.txt", 1, "17180");
+        assert_eq!(outcome(&unknown, &answers), Outcome::Unknown);
+        assert_eq!(
+            report(&unknown, &Outcome::Unknown),
+            "???? input.txt part 1: 17180 (no known answer)"
+        );
+    }
+
+    #[test]
+    fn watch_directory() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let mut watcher = Watcher::new(std::slice::from_ref(&dir)).unwrap();
+        fs::write(dir.join(".lib.rs.swp"), "").unwrap();
+        fs::write(dir.join("lib.rs"), "fn main() {}").unwrap();
+        fs::write(dir.join("lib.rs"), "fn main() {}\n").unwrap();
+        assert_eq!(
+            watcher.wait().unwrap(),
+            BTreeSet::from([dir.join("lib.rs")])
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,52 @@
+use common::{parse, ParseError, Solution};
+
+// Parse the puzzle input
+fn parse_lines(s: &str) -> Result<Vec<String>, ParseError> {
+    Ok(parse::lines(s).map(|line| line.text.to_string()).collect())
+}
+
+pub struct Day{{NN}};
+
+impl Solution for Day{{NN}} {
+    const DAY: u8 = {{N}};
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(&self, lines: &Self::Input) -> usize {
+        lines.len()
+    }
+
+    fn part2(&self, lines: &Self::Input) -> usize {
+        lines.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Replace with the answers to the example in the puzzle description
+    #[test]
+    fn part1() {
+        let input = Day{{NN}}.parse_file("test_input.txt");
+        assert_eq!(Day{{NN}}.part1(&input), 0);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day{{NN}}.parse_file("test_input.txt");
+        assert_eq!(Day{{NN}}.part2(&input), 0);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day{{NN}});
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+use day_{{NN}}::Day{{NN}};
+
+fn print_answers(filepath: &str) {
+    let input = Day{{NN}}.parse_file(filepath);
+    println!("Answer to part 1: {}", Day{{NN}}.part1(&input));
+    println!("Answer to part 2: {}", Day{{NN}}.part2(&input));
+}
+
+fn main() {
+    print_answers("test_input.txt");
+    println!("=========================");
+    print_answers("input.txt");
+}
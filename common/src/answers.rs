@@ -0,0 +1,145 @@
+use crate::solution::Solution;
+use crate::vault;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// The name of the answers manifest, found in each day's `res/` directory
+pub const MANIFEST: &str = "answers.txt";
+
+// A known answer for a part of a puzzle, for a given puzzle input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+// Parse an answers manifest. Each line is of the form:
+//
+// <input> <part> <answer>
+//
+// e.g.
+//
+// input.txt 1 24000
+//
+// Empty lines and lines starting with `#` are ignored. Answers spanning
+// multiple lines (e.g. day 10's image) are written with `\n` between lines.
+pub fn parse(s: &str) -> Result<Vec<Answer>, String> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let mut words = line.trim().splitn(3, ' ');
+            match (words.next(), words.next(), words.next()) {
+                (Some(input), Some(part @ ("1" | "2")), Some(answer)) => Ok(Answer {
+                    input: input.to_string(),
+                    part: part.parse().expect("Part is either 1 or 2"),
+                    answer: answer.replace("\\n", "\n"),
+                }),
+                _ => Err(format!(
+                    "Line {}: expected `<input> <part> <answer>`, found {line:?}",
+                    index + 1
+                )),
+            }
+        })
+        .collect()
+}
+
+// Load the answers manifest from a `res/` directory, if there is one
+pub fn load(res_dir: &str) -> io::Result<Option<Vec<Answer>>> {
+    let path = Path::new(res_dir).join(MANIFEST);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    parse(&fs::read_to_string(path)?)
+        .map(Some)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+// Answers are compared ignoring line endings (found on WSL) and trailing whitespace
+pub fn normalise(answer: &str) -> String {
+    answer.replace("\r\n", "\n").trim_end().to_string()
+}
+
+// Check a solution against its answers manifest, for use in tests
+//
+// Each puzzle input is solved with its parameters set up from `aoc.toml` (e.g.
+// day 15's row for `test_input.txt`), on a solution fresh from `make`. Puzzle
+// inputs (or the manifest itself) which are missing are skipped, as the real
+// puzzle inputs are not always available; as are encrypted puzzle inputs
+// without a key to decrypt them.
+pub fn check<S: Solution>(make: impl Fn() -> S) {
+    let answers = load(S::RES_DIR)
+        .unwrap_or_else(|error| panic!("Cannot load {MANIFEST} for day {}: {error}", S::DAY));
+    let Some(answers) = answers else {
+        eprintln!("Skipping day {}: no {MANIFEST}", S::DAY);
+        return;
+    };
+    for Answer {
+        input,
+        part,
+        answer,
+    } in answers
+    {
+        let solution = make().configured(&input);
+        match solution.resolve_input(&input) {
+            Err(_) => {
+                eprintln!("Skipping day {} with {input}: not found", S::DAY);
+                continue;
+            }
+            Ok(path) if vault::is_locked(&path) => {
+                eprintln!(
+                    "Skipping day {} with {input}: encrypted, and no key",
+                    S::DAY
+                );
+                continue;
+            }
+            Ok(_) => {}
+        }
+        let parsed = solution
+            .parse_input(&input)
+            .unwrap_or_else(|error| panic!("{error}"));
+        let actual = match part {
+            1 => solution.part1(&parsed).to_string(),
+            _ => solution.part2(&parsed).to_string(),
+        };
+        assert_eq!(
+            normalise(&actual),
+            normalise(&answer),
+            "Day {} part {part} with {input}",
+            S::DAY
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest() {
+        let answers = parse("# Comment\n\ninput.txt 1 24000\ninput.txt 2 #.\\n.#\n").unwrap();
+        assert_eq!(
+            answers,
+            vec![
+                Answer {
+                    input: "input.txt".to_string(),
+                    part: 1,
+                    answer: "24000".to_string(),
+                },
+                Answer {
+                    input: "input.txt".to_string(),
+                    part: 2,
+                    answer: "#.\n.#".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_manifest_error() {
+        assert!(parse("input.txt 3 24000").is_err());
+        assert!(parse("input.txt").is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::solution::{DynSolution, Solution};
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// The configuration file, found at the root of the repository
+pub const CONFIG_NAME: &str = "aoc.toml";
+
+// Environment variable pointing at a configuration file to use instead
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+// Tunable parameters for each day (e.g. day 15's row), which can differ
+// between the example and the real puzzle input
+//
+// e.g.
+//
+// [day_15]
+// row = 2000000
+// bounds = [0, 4000000]
+//
+// [day_15."test_input.txt"]
+// row = 10
+// bounds = [0, 20]
+//
+// Parameters in a day's table apply to every input, unless overridden by the
+// table for the input itself (matched by file name). Each day is given its
+// parameters as text e.g. "10" or "0,20" (see `Solution::set_param`).
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    table: toml::Table,
+}
+
+// Turn a value from the configuration file into the text given to a day
+fn to_text(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Integer(i) => Some(i.to_string()),
+        toml::Value::Float(f) => Some(f.to_string()),
+        toml::Value::Boolean(b) => Some(b.to_string()),
+        toml::Value::Array(values) => values
+            .iter()
+            .map(to_text)
+            .collect::<Option<Vec<_>>>()
+            .map(|values| values.join(",")),
+        _ => None,
+    }
+}
+
+impl Config {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        s.parse::<toml::Table>()
+            .map(|table| Config { table })
+            .map_err(|error| error.to_string())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display()))?;
+        Config::parse(&contents)
+            .map_err(|error| format!("Cannot parse {}: {error}", path.display()))
+    }
+
+    // `$AOC_CONFIG` if set, otherwise `aoc.toml` at the root of the repository
+    pub fn default_path() -> PathBuf {
+        match env::var_os(CONFIG_VAR) {
+            Some(path) => PathBuf::from(path),
+            None => Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join(CONFIG_NAME),
+        }
+    }
+
+    // Load the default configuration file, if there is one
+    pub fn load_default() -> Result<Self, String> {
+        let path = Config::default_path();
+        if path.is_file() {
+            Config::load(&path)
+        } else {
+            Ok(Config::default())
+        }
+    }
+
+    // The parameters for a day's puzzle input, in the order they should be set
+    pub fn params(&self, day: u8, input: &str) -> Result<Vec<(String, String)>, String> {
+        let name = format!("day_{:02}", day);
+        let Some(day_table) = self.table.get(&name) else {
+            return Ok(Vec::new());
+        };
+        let day_table = day_table
+            .as_table()
+            .ok_or_else(|| format!("Expected [{name}] to be a table"))?;
+        let file = Path::new(input)
+            .file_name()
+            .and_then(|file| file.to_str())
+            .unwrap_or(input);
+
+        let mut params = Vec::new();
+        let mut add = |key: &str, value: &toml::Value, table: &str| {
+            let text = to_text(value)
+                .ok_or_else(|| format!("Unable to use {table}.{key} as a parameter"))?;
+            params.push((key.to_string(), text));
+            Ok::<(), String>(())
+        };
+        for (key, value) in day_table.iter().filter(|(_, value)| !value.is_table()) {
+            add(key, value, &name)?;
+        }
+        if let Some(input_table) = day_table.get(file).and_then(|value| value.as_table()) {
+            for (key, value) in input_table {
+                add(key, value, &format!("{name}.{file:?}"))?;
+            }
+        }
+        Ok(params)
+    }
+
+    // Set the parameters for a puzzle input on a day
+    pub fn configure<S: Solution>(&self, mut solution: S, input: &str) -> Result<S, String> {
+        for (name, value) in self.params(S::DAY, input)? {
+            solution.set_param(&name, &value)?;
+        }
+        Ok(solution)
+    }
+
+    // As `configure`, but for a day whose type has been erased
+    pub fn configure_dyn(&self, solution: &mut dyn DynSolution, input: &str) -> Result<(), String> {
+        for (name, value) in self.params(solution.day(), input)? {
+            solution.set_param(&name, &value)?;
+        }
+        Ok(())
+    }
+}
+
+// Parse the text of a parameter e.g. "10"
+pub fn value<T: FromStr>(name: &str, value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value
+        .trim()
+        .parse::<T>()
+        .map_err(|error| format!("Cannot parse parameter {name} from {value:?}: {error}"))
+}
+
+// Parse the text of a parameter which is a pair e.g. "0,20" (i.e. `[0, 20]`)
+pub fn pair<T: FromStr>(name: &str, text: &str) -> Result<(T, T), String>
+where
+    T::Err: Display,
+{
+    match text.split(',').collect::<Vec<_>>()[..] {
+        [a, b] => Ok((value(name, a)?, value(name, b)?)),
+        _ => Err(format!(
+            "Expected parameter {name} to be a pair, found {text:?}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        [day_15]
+        row = 2000000
+        bounds = [0, 4000000]
+
+        [day_15."test_input.txt"]
+        row = 10
+    "#;
+
+    #[test]
+    fn params_by_input() {
+        let config = Config::parse(CONFIG).unwrap();
+        let params = |input| config.params(15, input).unwrap();
+        assert_eq!(
+            params("res/test_input.txt"),
+            vec![
+                ("bounds".to_string(), "0,4000000".to_string()),
+                ("row".to_string(), "2000000".to_string()),
+                ("row".to_string(), "10".to_string()),
+            ]
+        );
+        assert_eq!(params("input.txt").len(), 2);
+        assert!(config.params(1, "input.txt").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_values() {
+        assert_eq!(value::<u32>("row", " 10"), Ok(10));
+        assert!(value::<u32>("row", "-1").is_err());
+        assert_eq!(pair::<i32>("bounds", "0,20"), Ok((0, 20)));
+        assert!(pair::<i32>("bounds", "0").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::answers;
+use crate::config::Config;
+use crate::generate::{Generated, Rng};
+use crate::solution::Solution;
+use crate::vault;
+use std::fmt;
+use std::ops::Range;
+
+// Another way of solving a part of a day's puzzle (e.g. a simpler but slower
+// algorithm), which must always give the same answer as the day's own part
+pub struct Alternative<S: Solution> {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&S, &S::Input) -> String,
+}
+
+// The answer from an alternative, next to the answer from the day's own part
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub part: u8,
+    pub name: &'static str,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Comparison {
+    pub fn agrees(&self) -> bool {
+        answers::normalise(&self.expected) == answers::normalise(&self.actual)
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.agrees() {
+            write!(f, "part {} {}: agrees", self.part, self.name)
+        } else {
+            write!(
+                f,
+                "part {} {}: gave {:?}, but expected {:?}",
+                self.part, self.name, self.actual, self.expected
+            )
+        }
+    }
+}
+
+// Solve a parsed puzzle input with every alternative, and compare each answer
+// with the day's own
+pub fn compare<S: Solution>(solution: &S, input: &S::Input) -> Vec<Comparison> {
+    let mut expected: [Option<String>; 2] = [None, None];
+    solution
+        .alternatives()
+        .into_iter()
+        .map(|alternative| {
+            let part = alternative.part;
+            let expected = expected[part as usize - 1]
+                .get_or_insert_with(|| match part {
+                    1 => solution.part1(input).to_string(),
+                    2 => solution.part2(input).to_string(),
+                    _ => panic!("There is no part {part}; only parts 1 and 2"),
+                })
+                .clone();
+            Comparison {
+                part,
+                name: alternative.name,
+                expected,
+                actual: (alternative.solve)(solution, input),
+            }
+        })
+        .collect()
+}
+
+// Check that every alternative agrees with the day's own parts, for use in tests
+//
+// The alternatives are compared on each of the named puzzle inputs (set up
+// from `aoc.toml`, and skipped if missing as in `answers::check`), then on
+// an input generated from each of the seeds. Any disagreement panics with
+// what is needed to reproduce it.
+pub fn check<S: Solution>(make: impl Fn() -> S, inputs: &[&str], seeds: Range<u64>, size: usize) {
+    let config = Config::load_default().unwrap_or_else(|error| panic!("{error}"));
+    for name in inputs {
+        let solution = config
+            .configure(make(), name)
+            .unwrap_or_else(|error| panic!("{error}"));
+        match solution.resolve_input(name) {
+            Err(_) => {
+                eprintln!("Skipping day {} {name}: not found", S::DAY);
+                continue;
+            }
+            Ok(path) if vault::is_locked(&path) => {
+                eprintln!("Skipping day {} {name}: encrypted, and no key", S::DAY);
+                continue;
+            }
+            Ok(_) => {}
+        }
+        let input = solution.parse_file(name);
+        for comparison in compare(&solution, &input) {
+            assert!(comparison.agrees(), "Day {} {name}: {comparison}", S::DAY);
+        }
+    }
+    for seed in seeds {
+        let mut solution = make();
+        let Some(Generated { input, params }) = solution.generate(&mut Rng::new(seed), size) else {
+            panic!("Day {} cannot generate puzzle inputs", S::DAY);
+        };
+        for (name, value) in &params {
+            solution
+                .set_param(name, value)
+                .unwrap_or_else(|error| panic!("{error}"));
+        }
+        let parsed = solution.parse(&input).unwrap_or_else(|error| {
+            panic!(
+                "Day {} generated {input:?} (seed {seed}, size {size}): {error}",
+                S::DAY
+            )
+        });
+        for comparison in compare(&solution, &parsed) {
+            assert!(
+                comparison.agrees(),
+                "Day {} seed {seed}, size {size}: {comparison}\n{params:?}\n{input}",
+                S::DAY
+            );
+        }
+    }
+}
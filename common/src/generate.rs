@@ -0,0 +1,143 @@
+use crate::solution::Solution;
+use std::fmt::Display;
+use std::ops::Range;
+
+// A small, seedable source of random numbers (SplitMix64), so that a
+// generated puzzle input can always be reproduced from its seed
+//
+// This is not suitable for anything but generating puzzle inputs.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    // A number from 0 up to (but not including) `n`
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Cannot pick a number below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // A number from `low` up to (and including) `high`
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "Cannot pick a number between {low} and {high}");
+        let span = (high as i128 - low as i128 + 1) as u128;
+        (low as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    // True with a probability of `p` (from 0.0 to 1.0)
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// A generated puzzle input, along with any parameters the day needs to solve
+// it (e.g. day 15's bounds, which depend on the size of the input)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub params: Vec<(String, String)>,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Generated {
+            input,
+            params: Vec::new(),
+        }
+    }
+
+    pub fn param(mut self, name: &str, value: impl Display) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+// Check that a day generates puzzle inputs which it can parse and solve, for
+// use in tests
+//
+// Each seed is tried at each size, and must generate the same puzzle input
+// every time.
+pub fn check<S: Solution>(make: impl Fn() -> S, seeds: Range<u64>, sizes: &[usize]) {
+    for seed in seeds {
+        for &size in sizes {
+            let mut solution = make();
+            let generate = |solution: &S| {
+                solution
+                    .generate(&mut Rng::new(seed), size)
+                    .unwrap_or_else(|| panic!("Day {} cannot generate puzzle inputs", S::DAY))
+            };
+            let generated = generate(&solution);
+            assert_eq!(
+                generated,
+                generate(&solution),
+                "Day {} seed {seed}, size {size}",
+                S::DAY
+            );
+            for (name, value) in &generated.params {
+                solution
+                    .set_param(name, value)
+                    .unwrap_or_else(|error| panic!("{error}"));
+            }
+            let input = solution
+                .parse(&generated.input)
+                .unwrap_or_else(|error| panic!("Day {} seed {seed}, size {size}: {error}", S::DAY));
+            solution.part1(&input);
+            solution.part2(&input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..10).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn within_range() {
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.between(-2, 2)));
+        }
+        assert_eq!(rng.between(i64::MIN, i64::MIN), i64::MIN);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,322 @@
+use crate::parse::{self, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// A position within a grid, where x increases to the east and y increases
+// to the south (i.e. the top-left is (0, 0), as the puzzle inputs are read)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Coord {
+    pub fn new(x: usize, y: usize) -> Self {
+        Coord { x, y }
+    }
+
+    // Move by an offset, or `None` if that would go below 0 on either axis
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Coord {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    // Move one step in a direction, or `None` if that would go below 0 on either axis
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.delta();
+        self.offset(dx, dy)
+    }
+
+    pub fn manhattan(self, other: Coord) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    // The 4 directions along the axes
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    // All 8 directions, including diagonals
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    // The change in (x, y) for one step in this direction
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+}
+
+// A rectangular 2D grid, stored row by row in a single vector
+//
+// Indexed by `Coord` i.e. `grid[Coord::new(x, y)]` rather than `grid[y][x]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // Create a grid where every cell has the same value
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // Parse a map of characters (e.g. a puzzle input) into a grid, where `f`
+    // turns each character into a cell, or `None` if the character is invalid
+    //
+    // e.g.
+    //
+    // "30373\r\n25512\r\n"
+    //
+    // becomes a grid 5 wide and 2 high. Every row must be the same width.
+    pub fn parse(
+        s: &str,
+        f: impl Fn(char) -> Option<T>,
+        message: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in parse::lines(s) {
+            for (index, c) in line.text.char_indices() {
+                match f(c) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        let part = &line.text[index..index + c.len_utf8()];
+                        return Err(line.error(part, message));
+                    }
+                }
+            }
+            let row_width = line.text.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(line.error(line.text, "Expected every row to be the same width"));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.contains(coord)
+            .then(|| &self.cells[coord.y * self.width + coord.x])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if self.contains(coord) {
+            Some(&mut self.cells[coord.y * self.width + coord.x])
+        } else {
+            None
+        }
+    }
+
+    // Every coordinate within the grid, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |index| Coord::new(index % width, index / width))
+    }
+
+    // Every cell alongside its coordinate, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    // Find the coordinate of the first cell (row by row) matching a predicate
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.iter()
+            .find(|(_, cell)| f(cell))
+            .map(|(coord, _)| coord)
+    }
+
+    // Move one step in a direction, or `None` if that would leave the grid
+    pub fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        coord.step(direction).filter(|&coord| self.contains(coord))
+    }
+
+    // The neighbours of a coordinate in the given directions, which are within the grid
+    pub fn neighbours<'a>(
+        &'a self,
+        coord: Coord,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Coord> + 'a {
+        directions
+            .iter()
+            .filter_map(move |&direction| self.step(coord, direction))
+    }
+
+    // The (up to) 4 neighbours of a coordinate along the axes
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbours(coord, &Direction::ORTHOGONAL)
+    }
+
+    // The (up to) 8 neighbours of a coordinate, including diagonals
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbours(coord, &Direction::ALL)
+    }
+
+    // Every coordinate from a coordinate (exclusive) to the edge of the grid in a direction
+    pub fn ray(&self, coord: Coord, direction: Direction) -> impl Iterator<Item = Coord> + '_ {
+        std::iter::successors(self.step(coord, direction), move |&coord| {
+            self.step(coord, direction)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Note: `chunks` panics with a width of 0
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // Render the grid as ASCII art, one line per row
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).chain(['\n']).collect::<String>())
+            .collect::<String>()
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("Coord {coord} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("Coord {coord} is outside the grid"))
+    }
+}
+
+// Grids of characters render as themselves
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|&c| c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\r\ndef\r\n", Some, "").unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coord::new(2, 1)], 'f');
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let error = Grid::parse("ab\nabc", Some, "").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Grid::parse("ab\na?", |c| c.is_alphabetic().then_some(c), "Bad").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "?"));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        let corner = grid.neighbours4(Coord::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Coord::new(1, 0), Coord::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Coord::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn rows_columns_and_rays() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+        let ray = grid.ray(Coord::new(0, 0), Direction::East);
+        assert_eq!(ray.map(|coord| grid[coord]).collect::<String>(), "bc");
+        assert_eq!(grid.ray(Coord::new(0, 0), Direction::North).count(), 0);
+    }
+}
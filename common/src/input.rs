@@ -0,0 +1,175 @@
+use crate::parse::ParseError;
+use crate::vault;
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Cursor, Read};
+use std::path::{Path, PathBuf};
+
+// Environment variable pointing at a directory of puzzle inputs, laid out in
+// the same way as this repository i.e. `$AOC_INPUT_DIR/day_NN/input.txt`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// Anything that can go wrong while reading in and parsing a puzzle input
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{error}"),
+            Error::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+// The name used to read a puzzle input from stdin, rather than from a file
+pub const STDIN: &str = "-";
+
+// Find a puzzle input, regardless of the current working directory
+//
+// A `name` with a directory in it (e.g. `./input.txt` or `/tmp/input.txt`) is a
+// path, absolute or relative to the current working directory as usual for
+// command line arguments. Otherwise it is the name of a file in the day's own
+// directories, in order:
+// - `$AOC_INPUT_DIR/day_NN/<name>`, if the environment variable is set
+// - `<res_dir>/<name>`, where `res_dir` is the day's own `res/` directory
+//
+// Note: a bare name is never looked for in the current working directory, or
+// running every day from one day's `res/` would give them all its `input.txt`
+//
+// In each place, an encrypted puzzle input (e.g. `input.txt.enc`, see `vault`)
+// is found if the puzzle input itself is not.
+pub fn resolve(day: u8, res_dir: &str, name: &str) -> io::Result<PathBuf> {
+    resolve_in(input_dir().as_deref(), day, res_dir, name)
+}
+
+// As `resolve`, but with `input_dir` in place of `$AOC_INPUT_DIR`
+pub fn resolve_in(
+    input_dir: Option<&Path>,
+    day: u8,
+    res_dir: &str,
+    name: &str,
+) -> io::Result<PathBuf> {
+    let candidates = candidates(input_dir, day, res_dir, name);
+    candidates
+        .iter()
+        .flat_map(|path| [path.clone(), vault::encrypted_path(path)])
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            let searched = candidates
+                .iter()
+                .map(|path| format!("  {}", path.display()))
+                .collect::<Vec<_>>()
+                .join("\n");
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Cannot find puzzle input {name:?}, searched:\n{searched}"),
+            )
+        })
+}
+
+// The directory `$AOC_INPUT_DIR` points at, if it is set
+pub fn input_dir() -> Option<PathBuf> {
+    env::var_os(INPUT_DIR_VAR).map(PathBuf::from)
+}
+
+// Whether `name` is a path, rather than just the name of a file
+pub fn is_path(name: &str) -> bool {
+    Path::new(name)
+        .parent()
+        .is_some_and(|parent| !parent.as_os_str().is_empty())
+}
+
+fn candidates(input_dir: Option<&Path>, day: u8, res_dir: &str, name: &str) -> Vec<PathBuf> {
+    if is_path(name) {
+        return vec![PathBuf::from(name)];
+    }
+    let mut candidates = Vec::new();
+    if let Some(dir) = input_dir {
+        candidates.push(dir.join(format!("day_{:02}", day)).join(name));
+    }
+    candidates.push(Path::new(res_dir).join(name));
+    candidates
+}
+
+// The directory new puzzle inputs (e.g. from `aoc fetch`) are saved to:
+// `$AOC_INPUT_DIR/day_NN/` if the environment variable is set, otherwise
+// the day's own `res/` directory
+pub fn save_dir(day: u8, res_dir: &str) -> PathBuf {
+    save_dir_in(input_dir().as_deref(), day, res_dir)
+}
+
+// As `save_dir`, but with `input_dir` in place of `$AOC_INPUT_DIR`
+pub fn save_dir_in(input_dir: Option<&Path>, day: u8, res_dir: &str) -> PathBuf {
+    match input_dir {
+        Some(dir) => dir.join(format!("day_{:02}", day)),
+        None => PathBuf::from(res_dir),
+    }
+}
+
+// Open a puzzle input for reading: stdin if `name` is `-`, otherwise the
+// file found by `resolve`, decrypted if need be
+pub fn open(day: u8, res_dir: &str, name: &str) -> io::Result<Box<dyn Read>> {
+    if name == STDIN {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let path = resolve(day, res_dir, name)?;
+    if vault::is_encrypted(&path) {
+        let key = vault::Key::from_env()?;
+        return Ok(Box::new(Cursor::new(vault::read(&path, key.as_ref())?)));
+    }
+    Ok(Box::new(File::open(path)?))
+}
+
+// Read a puzzle input in as a string, from any reader
+pub fn read(mut reader: impl Read) -> io::Result<String> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search() {
+        assert_eq!(
+            candidates(None, 5, "res", "input.txt"),
+            vec![PathBuf::from("res/input.txt")]
+        );
+        assert_eq!(
+            candidates(Some(Path::new("inputs")), 5, "res", "test_input.txt"),
+            vec![
+                PathBuf::from("inputs/day_05/test_input.txt"),
+                PathBuf::from("res/test_input.txt")
+            ]
+        );
+        assert_eq!(
+            candidates(Some(Path::new("inputs")), 5, "res", "./input.txt"),
+            vec![PathBuf::from("./input.txt")]
+        );
+        assert_eq!(
+            candidates(None, 5, "res", "/tmp/big.txt"),
+            vec![PathBuf::from("/tmp/big.txt")]
+        );
+    }
+}
@@ -0,0 +1,18 @@
+// Code shared between the days, and between the days and the `aoc` runner
+pub mod answers;
+pub mod config;
+pub mod differential;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod repl;
+pub mod simulation;
+pub mod snapshot;
+pub mod solution;
+pub mod vault;
+pub mod visualise;
+
+pub use grid::{Coord, Direction, Grid};
+pub use parse::{Line, ParseError};
+pub use solution::{DynSolution, Solution};
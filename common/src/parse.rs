@@ -0,0 +1,319 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// An error found while parsing a puzzle input, pointing at the offending text
+//
+// e.g.
+//
+// Cannot parse sensor X as i32
+//   --> input.txt:3:13
+//    |
+//  3 | Sensor at x=abc, y=18: closest beacon is at x=-2, y=15
+//    |             ^^^
+//
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub file: Option<String>,
+    pub line: usize,     // Starts from 1
+    pub column: usize,   // Starts from 1, and counts characters rather than bytes
+    pub text: String,    // The offending text itself
+    pub context: String, // The whole line containing the offending text
+}
+
+impl ParseError {
+    // Create an error for something missing from the end of a puzzle input
+    pub fn end_of_input(s: &str, message: &str) -> Self {
+        let (number, text) = s
+            .trim_end()
+            .split('\n')
+            .enumerate()
+            .last()
+            .map(|(index, text)| (index + 1, text.trim_end()))
+            .unwrap_or((1, ""));
+        ParseError {
+            message: message.to_string(),
+            file: None,
+            line: number,
+            column: text.chars().count() + 1,
+            text: String::new(),
+            context: text.to_string(),
+        }
+    }
+
+    // Record which file was being parsed, as parsers themselves only see the contents
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let file = self.file.as_deref().unwrap_or("<input>");
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{margin}--> {file}:{}:{}", self.line, self.column)?;
+        writeln!(f, "{margin} |")?;
+        writeln!(f, "{number} | {}", self.context)?;
+        write!(
+            f,
+            "{margin} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.text.chars().count().max(1))
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// A line of a puzzle input, remembering where it came from so that
+// errors can point at it
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize, // Starts from 1
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // Create an error pointing at `part` of this line
+    //
+    // Note: `part` should be a slice of `self.text` (e.g. from `split_once`),
+    // so that we can work out its column. Otherwise, point at the whole line.
+    pub fn error(&self, part: &str, message: &str) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + part.len() <= self.text.len());
+        let (column, text) = match offset {
+            Some(offset) => (self.text[..offset].chars().count() + 1, part),
+            None => (1, self.text),
+        };
+        ParseError {
+            message: message.to_string(),
+            file: None,
+            line: self.number,
+            column,
+            text: text.to_string(),
+            context: self.text.to_string(),
+        }
+    }
+
+    // Parse `part` of this line e.g. as an integer
+    pub fn parse<T: FromStr>(&self, part: &str, message: &str) -> Result<T, ParseError> {
+        part.trim()
+            .parse::<T>()
+            .map_err(|_| self.error(part, message))
+    }
+
+    // Parse every integer within `part` of this line (see `integers`)
+    pub fn integers<T: FromStr>(&self, part: &str, message: &str) -> Result<Vec<T>, ParseError> {
+        integers(part)
+            .map(|integer| self.parse::<T>(integer, message))
+            .collect()
+    }
+
+    // Check that every character of this line is valid, or point at the first one which is not
+    pub fn check_chars(
+        &self,
+        valid: impl Fn(char) -> bool,
+        message: &str,
+    ) -> Result<(), ParseError> {
+        match self.text.char_indices().find(|&(_, c)| !valid(c)) {
+            Some((index, c)) => Err(self.error(&self.text[index..index + c.len_utf8()], message)),
+            None => Ok(()),
+        }
+    }
+
+    // Split `part` of this line into two around the first `delimiter`
+    pub fn split_once(
+        &self,
+        part: &'a str,
+        delimiter: &str,
+        message: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error(part, message))
+    }
+}
+
+// The byte order mark some editors (e.g. on Windows) add to the start of a file
+const BOM: char = '\u{feff}';
+
+// Split a puzzle input into every line, normalising each one by stripping
+// any byte order mark, carriage returns (found on WSL) and trailing whitespace
+//
+// Note: lines keep pointing into `s`, so that errors can still find their column
+fn normalised_lines(s: &str) -> impl Iterator<Item = Line<'_>> {
+    s.strip_prefix(BOM)
+        .unwrap_or(s)
+        .split('\n')
+        .enumerate()
+        .map(|(index, text)| Line {
+            number: index + 1,
+            text: text.trim_end(),
+        })
+}
+
+// Split a puzzle input into lines, removing extraneous empty lines
+pub fn lines(s: &str) -> impl Iterator<Item = Line<'_>> {
+    normalised_lines(s).filter(|line| !line.text.is_empty())
+}
+
+// Split a puzzle input into groups of lines, where groups are separated
+// by empty lines
+//
+// e.g.
+//
+// "100\r\n200\r\n\r\n300\r\n"
+//
+// becomes:
+//
+// [["100", "200"], ["300"]]
+//
+pub fn groups(s: &str) -> Vec<Vec<Line<'_>>> {
+    let mut groups = vec![Vec::new()];
+    for line in normalised_lines(s) {
+        if line.text.is_empty() {
+            groups.push(Vec::new());
+        } else {
+            let group = groups.last_mut().expect("There is always a group");
+            group.push(line);
+        }
+    }
+    groups.retain(|group| !group.is_empty()); // Remove extraneous empty lines
+    groups
+}
+
+// Find every integer within some text, ignoring whatever surrounds them
+//
+// e.g.
+//
+// "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15"
+//
+// becomes:
+//
+// ["2", "-18", "-2", "15"]
+//
+// Note: a `-` directly before a digit is always taken as a sign
+pub fn integers(s: &str) -> impl DoubleEndedIterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        let is_signed =
+            bytes[index] == b'-' && bytes.get(index + 1).is_some_and(u8::is_ascii_digit);
+        if bytes[index].is_ascii_digit() || is_signed {
+            let start = index;
+            index += 1;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            tokens.push(&s[start..index]);
+        } else {
+            index += 1;
+        }
+    }
+    tokens.into_iter()
+}
+
+// A group of `key: value` lines, where each key may appear in any order
+//
+// e.g. (for day 11)
+//
+// Monkey 0:
+//   Starting items: 79, 98
+//   Operation: new = old * 19
+//
+// has the keys "Monkey 0", "Starting items" and "Operation".
+pub struct Record<'a> {
+    lines: Vec<Line<'a>>,
+    fields: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Record<'a> {
+    // Split every line into a key and a value around the first `separator`,
+    // trimming whitespace from both
+    pub fn new(lines: &[Line<'a>], separator: &str) -> Result<Self, ParseError> {
+        let fields = lines
+            .iter()
+            .map(|line| {
+                let message = format!("Expected a line of the form `key{separator} value`");
+                let (key, value) = line.split_once(line.text, separator, &message)?;
+                Ok((key.trim(), value.trim()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Record {
+            lines: lines.to_vec(),
+            fields,
+        })
+    }
+
+    // Find the value for a key, alongside its line (so that errors can point at it)
+    pub fn get(&self, key: &str) -> Option<(Line<'a>, &'a str)> {
+        self.fields
+            .iter()
+            .position(|(k, _)| *k == key)
+            .map(|index| (self.lines[index], self.fields[index].1))
+    }
+
+    // As `get`, but a missing key is an error pointing at the end of the record
+    pub fn field(&self, key: &str) -> Result<(Line<'a>, &'a str), ParseError> {
+        self.get(key).ok_or_else(|| match self.lines.last() {
+            Some(last) => last.error(last.text, &format!("Cannot find {key:?}")),
+            None => ParseError::end_of_input("", &format!("Cannot find {key:?}")),
+        })
+    }
+
+    // Every key, in the order they appear
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.fields.iter().map(|(key, _)| *key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalise_lines() {
+        let s = "\u{feff}1 \r\n\r\n \t\r\n2\t\n3\n";
+        let texts = lines(s).map(|line| line.text).collect::<Vec<_>>();
+        assert_eq!(texts, vec!["1", "2", "3"]);
+        let numbers = groups(s)
+            .iter()
+            .map(|group| group.iter().map(|line| line.number).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![vec![1], vec![4, 5]]);
+    }
+
+    #[test]
+    fn extract_integers() {
+        let s = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(
+            integers(s).collect::<Vec<_>>(),
+            vec!["2", "-18", "-2", "15"]
+        );
+        assert_eq!(integers("a - b -").count(), 0);
+
+        let line = Line { number: 1, text: s };
+        assert_eq!(line.integers::<i32>(s, "").unwrap(), vec![2, -18, -2, 15]);
+        let error = line.integers::<u8>(s, "Cannot parse as u8").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (18, "-18"));
+    }
+
+    #[test]
+    fn records() {
+        let lines = lines("Monkey 0:\n  Test: divisible by 23\n").collect::<Vec<_>>();
+        let record = Record::new(&lines, ":").unwrap();
+        assert_eq!(record.keys().collect::<Vec<_>>(), vec!["Monkey 0", "Test"]);
+        let (line, value) = record.field("Test").unwrap();
+        assert_eq!((line.number, value), (2, "divisible by 23"));
+        assert_eq!(record.field("Operation").unwrap_err().line, 2);
+
+        let invalid = super::lines("Monkey 0\n").collect::<Vec<_>>();
+        assert!(Record::new(&invalid, ":").is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+// A day's commands for `aoc repl`, to query a parsed puzzle input while
+// debugging (e.g. the positions covered in a row for day 15), rather than
+// printing from `main`
+//
+// A REPL can keep its own state between commands (e.g. day 14's sand, which
+// falls a number of units at a time).
+pub trait Repl {
+    // The usage of each command, and what it does, e.g. `("row <y>", "...")`
+    fn commands(&self) -> Vec<(&'static str, &'static str)>;
+
+    // Run a command with its arguments, giving back what to print
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+// The error for a command the day does not have
+pub fn unknown(command: &str) -> String {
+    format!("Unknown command {command:?} (see `help`)")
+}
+
+// Parse the argument at `index` of a command
+pub fn arg<T: FromStr>(args: &[&str], index: usize, name: &str) -> Result<T, String> {
+    let arg = args
+        .get(index)
+        .ok_or_else(|| format!("Expected <{name}>"))?;
+    arg.parse::<T>()
+        .map_err(|_| format!("Cannot parse <{name}> from {arg:?}"))
+}
+
+// Parse the argument at `index` of a command, if it was given
+pub fn arg_or<T: FromStr>(
+    args: &[&str],
+    index: usize,
+    name: &str,
+    default: T,
+) -> Result<T, String> {
+    match args.get(index) {
+        Some(_) => arg(args, index, name),
+        None => Ok(default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn args() {
+        assert_eq!(arg::<i32>(&["10", "x"], 0, "y"), Ok(10));
+        assert_eq!(
+            arg::<i32>(&["10", "x"], 1, "y"),
+            Err("Cannot parse <y> from \"x\"".to_string())
+        );
+        assert_eq!(arg::<i32>(&[], 0, "y"), Err("Expected <y>".to_string()));
+        assert_eq!(arg_or(&[], 0, "n", 1), Ok(1));
+        assert_eq!(arg_or(&["5"], 0, "n", 1), Ok(5));
+    }
+}
@@ -0,0 +1,191 @@
+use std::time::{Duration, Instant};
+
+type OnStep<'a, S> = Box<dyn FnMut(usize, &S) + 'a>;
+
+// A simulation which is run a step at a time until it is done, e.g. the sand
+// in day 14 (a step being a unit of sand) or the monkeys in day 11 (a round)
+//
+// Simulations are run by a `Driver`, which can stop them after a number of
+// steps or an amount of time, watch every step, and keep snapshots of them
+// along the way.
+pub trait Simulation {
+    // The state of the simulation, as kept by a checkpoint
+    type Snapshot;
+
+    fn step(&mut self);
+
+    fn is_done(&self) -> bool;
+
+    fn snapshot(&self) -> Self::Snapshot;
+}
+
+// Why a driver stopped running a simulation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    Done,
+    MaxSteps,
+    Timeout,
+}
+
+// What happened when a driver ran a simulation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run<T> {
+    pub stop: Stop,
+    pub steps: usize,
+    pub checkpoints: Vec<(usize, T)>, // The steps run so far, and the snapshot after them
+}
+
+// Runs a simulation until it is done, unless a limit is reached first
+//
+// A simulation stopped by a limit is left as it was, so it can be run again
+// (by any driver) to carry on from where it stopped.
+pub struct Driver<'a, S: Simulation> {
+    max_steps: Option<usize>,
+    timeout: Option<Duration>,
+    checkpoint_every: Option<usize>,
+    on_step: Option<OnStep<'a, S>>,
+}
+
+impl<'a, S: Simulation> Default for Driver<'a, S> {
+    fn default() -> Self {
+        Driver {
+            max_steps: None,
+            timeout: None,
+            checkpoint_every: None,
+            on_step: None,
+        }
+    }
+}
+
+impl<'a, S: Simulation> Driver<'a, S> {
+    // A driver without any limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_steps(mut self, steps: usize) -> Self {
+        self.max_steps = Some(steps);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    // Keep a snapshot after every `every` steps
+    pub fn checkpoints(mut self, every: usize) -> Self {
+        assert!(every > 0, "Cannot keep a checkpoint every 0 steps");
+        self.checkpoint_every = Some(every);
+        self
+    }
+
+    // Call `f` after every step, with the number of steps run so far
+    pub fn on_step(mut self, f: impl FnMut(usize, &S) + 'a) -> Self {
+        self.on_step = Some(Box::new(f));
+        self
+    }
+
+    pub fn run(mut self, simulation: &mut S) -> Run<S::Snapshot> {
+        let start = Instant::now();
+        let mut run = Run {
+            stop: Stop::Done,
+            steps: 0,
+            checkpoints: Vec::new(),
+        };
+        run.stop = loop {
+            if simulation.is_done() {
+                break Stop::Done;
+            }
+            if self
+                .max_steps
+                .is_some_and(|max_steps| run.steps >= max_steps)
+            {
+                break Stop::MaxSteps;
+            }
+            if self
+                .timeout
+                .is_some_and(|timeout| start.elapsed() >= timeout)
+            {
+                break Stop::Timeout;
+            }
+            simulation.step();
+            run.steps += 1;
+            if let Some(f) = &mut self.on_step {
+                f(run.steps, simulation);
+            }
+            if let Some(every) = self.checkpoint_every {
+                if run.steps.is_multiple_of(every) {
+                    run.checkpoints.push((run.steps, simulation.snapshot()));
+                }
+            }
+        };
+        run
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts up to a target, or forever without one
+    struct Counter {
+        count: usize,
+        target: Option<usize>,
+    }
+
+    impl Simulation for Counter {
+        type Snapshot = usize;
+
+        fn step(&mut self) {
+            self.count += 1;
+        }
+
+        fn is_done(&self) -> bool {
+            self.target == Some(self.count)
+        }
+
+        fn snapshot(&self) -> usize {
+            self.count
+        }
+    }
+
+    #[test]
+    fn run_to_end() {
+        let mut counter = Counter {
+            count: 0,
+            target: Some(10),
+        };
+        let mut seen = Vec::new();
+        let run = Driver::new()
+            .checkpoints(4)
+            .on_step(|steps, counter: &Counter| seen.push((steps, counter.count)))
+            .run(&mut counter);
+        assert_eq!(run.stop, Stop::Done);
+        assert_eq!(run.steps, 10);
+        assert_eq!(run.checkpoints, [(4, 4), (8, 8)]);
+        assert_eq!(seen, (1..=10).map(|n| (n, n)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn limits() {
+        let mut counter = Counter {
+            count: 0,
+            target: Some(10),
+        };
+        let run = Driver::new().max_steps(6).run(&mut counter);
+        assert_eq!((run.stop, run.steps, counter.count), (Stop::MaxSteps, 6, 6));
+        let run = Driver::new().max_steps(6).run(&mut counter);
+        assert_eq!((run.stop, run.steps, counter.count), (Stop::Done, 4, 10));
+
+        let mut counter = Counter {
+            count: 0,
+            target: None,
+        };
+        let run = Driver::new()
+            .timeout(Duration::from_millis(20))
+            .run(&mut counter);
+        assert_eq!(run.stop, Stop::Timeout);
+        assert_eq!(run.steps, counter.count);
+    }
+}
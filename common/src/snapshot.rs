@@ -0,0 +1,188 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Snapshots of what a day renders over several lines (e.g. day 10's image, or
+// day 14's scan), kept in the day's `res/snapshots/` so that tests can check
+// the rendering hasn't changed, and show how it has if it has
+//
+// When a rendering changes on purpose, run the tests with
+// `AOC_UPDATE_SNAPSHOTS=1` to accept the new renderings as the snapshots.
+
+// Environment variable which, when set, updates snapshots rather than checking them
+pub const UPDATE_VAR: &str = "AOC_UPDATE_SNAPSHOTS";
+
+// The directory within a day's `res/` directory holding its snapshots
+pub const DIR: &str = "snapshots";
+
+// Lines either side of a change shown by `diff`
+const CONTEXT: usize = 2;
+
+pub fn path(res_dir: &str, name: &str) -> PathBuf {
+    Path::new(res_dir).join(DIR).join(format!("{name}.txt"))
+}
+
+// Renderings are compared ignoring line endings (e.g. day 10's `\r\n`), and
+// always end with a newline
+fn normalise(rendering: &str) -> String {
+    let rendering = rendering.replace("\r\n", "\n");
+    if rendering.ends_with('\n') {
+        rendering
+    } else {
+        rendering + "\n"
+    }
+}
+
+// A line by line diff, with `-` for lines only expected, and `+` for lines
+// only found; unchanged lines away from any change are left out
+pub fn diff(expected: &str, actual: &str) -> String {
+    let (a, b) = (
+        expected.lines().collect::<Vec<_>>(),
+        actual.lines().collect::<Vec<_>>(),
+    );
+    // The length of the longest common subsequence of `a[i..]` and `b[j..]`
+    let mut lcs = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            lines.push((' ', a[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', a[i]));
+            i += 1;
+        } else {
+            lines.push(('+', b[j]));
+            j += 1;
+        }
+    }
+    let changed = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, (marker, _))| *marker != ' ')
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    if changed.is_empty() {
+        return String::new();
+    }
+    let near_change = |index: usize| {
+        changed
+            .iter()
+            .any(|&change| change.abs_diff(index) <= CONTEXT)
+    };
+    let (mut diff, mut skipping) = (String::new(), false);
+    for (index, (marker, line)) in lines.iter().enumerate() {
+        if near_change(index) {
+            diff.push_str(&format!("{marker} {line}\n"));
+            skipping = false;
+        } else if !skipping {
+            diff.push_str("  ...\n");
+            skipping = true;
+        }
+    }
+    diff
+}
+
+// How a rendering compares to its snapshot
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Same,
+    Missing,
+    Different(String), // The diff from the snapshot to the rendering
+    Updated,           // The snapshot was missing or different, and has been updated
+}
+
+// Compare a rendering against the snapshot at `path`, first updating the
+// snapshot to match if `update` is set
+pub fn compare(path: &Path, rendering: &str, update: bool) -> io::Result<Outcome> {
+    let rendering = normalise(rendering);
+    let snapshot = match fs::read_to_string(path) {
+        Ok(snapshot) => Some(normalise(&snapshot)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => return Err(error),
+    };
+    match (snapshot, update) {
+        (Some(snapshot), _) if snapshot == rendering => Ok(Outcome::Same),
+        (_, true) => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, rendering)?;
+            Ok(Outcome::Updated)
+        }
+        (None, false) => Ok(Outcome::Missing),
+        (Some(snapshot), false) => Ok(Outcome::Different(diff(&snapshot, &rendering))),
+    }
+}
+
+// Check a rendering against its snapshot in a day's `res/snapshots/`, for use in tests
+pub fn check(res_dir: &str, name: &str, rendering: &str) {
+    let path = path(res_dir, name);
+    let update = env::var_os(UPDATE_VAR).is_some();
+    let outcome = compare(&path, rendering, update)
+        .unwrap_or_else(|error| panic!("Cannot check snapshot {}: {error}", path.display()));
+    match outcome {
+        Outcome::Same => {}
+        Outcome::Updated => eprintln!("Updated snapshot {}", path.display()),
+        Outcome::Missing => panic!(
+            "No snapshot {}; run with {UPDATE_VAR}=1 to accept this rendering:\n{rendering}",
+            path.display()
+        ),
+        Outcome::Different(diff) => panic!(
+            "Rendering differs from snapshot {} (-snapshot, +rendering); run with {UPDATE_VAR}=1 to accept it:\n{diff}",
+            path.display()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_diff() {
+        assert_eq!(diff("a\nb\n", "a\nb\n"), "");
+        assert_eq!(diff("#.\n.#\n", "#.\n##\n"), "  #.\n- .#\n+ ##\n");
+        let expected = (0..10).map(|n| format!("{n}\n")).collect::<String>();
+        let actual = expected.replace("5\n", "five\n") + "10\n";
+        assert_eq!(
+            diff(&expected, &actual),
+            "  ...\n  3\n  4\n- 5\n+ five\n  6\n  7\n  8\n  9\n+ 10\n"
+        );
+    }
+
+    #[test]
+    fn compare_and_update() {
+        let dir = env::temp_dir().join(format!("aoc-snapshot-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join(DIR).join("image.txt");
+        assert_eq!(
+            compare(&path, "#.\r\n.#\r\n", false).unwrap(),
+            Outcome::Missing
+        );
+        assert_eq!(
+            compare(&path, "#.\r\n.#\r\n", true).unwrap(),
+            Outcome::Updated
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "#.\n.#\n");
+        assert_eq!(compare(&path, "#.\n.#", false).unwrap(), Outcome::Same);
+        assert_eq!(
+            compare(&path, "#.\n##\n", false).unwrap(),
+            Outcome::Different("  #.\n- .#\n+ ##\n".to_string())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "#.\n.#\n");
+        assert_eq!(compare(&path, "#.\n##\n", true).unwrap(), Outcome::Updated);
+        assert_eq!(compare(&path, "#.\n##\n", false).unwrap(), Outcome::Same);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
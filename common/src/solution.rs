@@ -0,0 +1,249 @@
+use crate::config::Config;
+use crate::differential::{self, Alternative, Comparison};
+use crate::generate::{Generated, Rng};
+use crate::input;
+use crate::parse::ParseError;
+use crate::repl::Repl;
+use crate::visualise::Frames;
+use std::any::Any;
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+// Every day solves its puzzle in the same two stages:
+// - parse the puzzle input into a typed model
+// - solve part 1 and part 2 using that model, producing a displayable answer
+//
+// Any tunable values a day needs (e.g. the row to check in day 15, which
+// differs between the example and the real input) live on the implementing
+// type itself, so that `Default` gives the values for the real input. They
+// can be set by name from `aoc.toml` or the command line (see `config`).
+pub trait Solution {
+    const DAY: u8;
+    // The day's own `res/` directory, i.e. `concat!(env!("CARGO_MANIFEST_DIR"), "/res")`
+    const RES_DIR: &'static str;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(&self, input: &Self::Input) -> Self::Part1;
+
+    fn part2(&self, input: &Self::Input) -> Self::Part2;
+
+    // Set a tunable value by name, from its text e.g. "10" or "0,20"
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let _ = value;
+        Err(format!("Day {} has no parameter {name:?}", Self::DAY))
+    }
+
+    // Set the tunable values from `aoc.toml` for a puzzle input, for when
+    // there is nothing better to do with an error than panic
+    fn configured(self, input: &str) -> Self
+    where
+        Self: Sized,
+    {
+        Config::load_default()
+            .and_then(|config| config.configure(self, input))
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    // Other ways of solving the parts, which must give the same answers (see `differential`)
+    fn alternatives(&self) -> Vec<Alternative<Self>>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    // Generate a random puzzle input of roughly the given size, if the day can
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let _ = (rng, size);
+        None
+    }
+
+    // Draw the frames of solving a part (1 or 2), returning false if the day
+    // has nothing to draw for it (see `visualise`)
+    fn visualise(&self, input: &Self::Input, part: u8, frames: &mut Frames) -> bool {
+        let _ = (input, part, frames);
+        false
+    }
+
+    // Commands to query a parsed puzzle input with in `aoc repl`, if the day has any
+    fn repl<'a>(&'a self, input: &'a Self::Input) -> Option<Box<dyn Repl + 'a>> {
+        let _ = input;
+        None
+    }
+
+    // Find a puzzle input by name or path (see `input::resolve`)
+    fn resolve_input(&self, name: &str) -> io::Result<PathBuf> {
+        input::resolve(Self::DAY, Self::RES_DIR, name)
+    }
+
+    // Open a puzzle input by name or path, or `-` for stdin (see `input::open`)
+    fn open_input(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        input::open(Self::DAY, Self::RES_DIR, name)
+    }
+
+    // Read in a puzzle input as a string from any reader, and then parse it
+    fn parse_reader(&self, reader: impl Read) -> Result<Self::Input, input::Error>
+    where
+        Self: Sized,
+    {
+        Ok(self.parse(&input::read(reader)?)?)
+    }
+
+    // Find a puzzle input, read it in as a string, and then parse it
+    fn parse_input(&self, name: &str) -> Result<Self::Input, input::Error>
+    where
+        Self: Sized,
+    {
+        let file = match name {
+            input::STDIN => "<stdin>".to_string(),
+            _ => self.resolve_input(name)?.display().to_string(),
+        };
+        self.parse_reader(self.open_input(name)?)
+            .map_err(|error| match error {
+                input::Error::Parse(error) => input::Error::Parse(error.in_file(&file)),
+                error => error,
+            })
+    }
+
+    // As `parse_input`, but for when there is nothing better to do with an error than panic
+    fn parse_file(&self, name: &str) -> Self::Input
+    where
+        Self: Sized,
+    {
+        self.parse_input(name)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+// `Solution` has associated types, so it cannot be used as a trait object.
+// This erases those types so that every day can be handled uniformly, e.g.
+// by keeping all of them in a `Vec<Box<dyn DynSolution>>`.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    // The day's own `res/` directory
+    fn res_dir(&self) -> &'static str;
+
+    // Where new puzzle inputs for this day are saved (see `input::save_dir`)
+    fn save_dir(&self) -> PathBuf;
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    fn resolve_input(&self, name: &str) -> io::Result<PathBuf>;
+
+    fn open_input(&self, name: &str) -> io::Result<Box<dyn Read>>;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn parse_reader(&self, reader: &mut dyn Read) -> Result<Box<dyn Any>, input::Error>;
+
+    fn parse_input(&self, name: &str) -> Result<Box<dyn Any>, input::Error>;
+
+    // Solve the given part (1 or 2) of the puzzle, using a model returned by `parse`
+    fn part(&self, input: &dyn Any, part: u8) -> String;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated>;
+
+    // Draw the frames of solving a part, using a model returned by `parse`
+    fn visualise(&self, input: &dyn Any, part: u8, frames: &mut Frames) -> bool;
+
+    // The day's REPL commands, using a model returned by `parse`
+    fn repl<'a>(&'a self, input: &'a dyn Any) -> Option<Box<dyn Repl + 'a>>;
+
+    // The part and name of each alternative (see `Solution::alternatives`)
+    fn alternative_names(&self) -> Vec<(u8, &'static str)>;
+
+    // Compare the alternatives with both parts, using a model returned by `parse`
+    fn compare(&self, input: &dyn Any) -> Vec<Comparison>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn res_dir(&self) -> &'static str {
+        S::RES_DIR
+    }
+
+    fn save_dir(&self) -> PathBuf {
+        input::save_dir(S::DAY, S::RES_DIR)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        Solution::set_param(self, name, value)
+    }
+
+    fn resolve_input(&self, name: &str) -> io::Result<PathBuf> {
+        Solution::resolve_input(self, name)
+    }
+
+    fn open_input(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        Solution::open_input(self, name)
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn parse_reader(&self, reader: &mut dyn Read) -> Result<Box<dyn Any>, input::Error> {
+        Ok(Box::new(Solution::parse_reader(self, reader)?))
+    }
+
+    fn parse_input(&self, name: &str) -> Result<Box<dyn Any>, input::Error> {
+        Ok(Box::new(Solution::parse_input(self, name)?))
+    }
+
+    fn part(&self, input: &dyn Any, part: u8) -> String {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was not parsed by this solution");
+        match part {
+            1 => self.part1(input).to_string(),
+            2 => self.part2(input).to_string(),
+            _ => panic!("There is no part {part}; only parts 1 and 2"),
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Solution::generate(self, rng, size)
+    }
+
+    fn visualise(&self, input: &dyn Any, part: u8, frames: &mut Frames) -> bool {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was not parsed by this solution");
+        Solution::visualise(self, input, part, frames)
+    }
+
+    fn repl<'a>(&'a self, input: &'a dyn Any) -> Option<Box<dyn Repl + 'a>> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was not parsed by this solution");
+        Solution::repl(self, input)
+    }
+
+    fn alternative_names(&self) -> Vec<(u8, &'static str)> {
+        self.alternatives()
+            .iter()
+            .map(|alternative| (alternative.part, alternative.name))
+            .collect()
+    }
+
+    fn compare(&self, input: &dyn Any) -> Vec<Comparison> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was not parsed by this solution");
+        differential::compare(self, input)
+    }
+}
@@ -0,0 +1,235 @@
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Puzzle inputs are not meant to be shared, so they can be kept in the
+// repository encrypted instead: `input.txt` as `input.txt.enc`, with a key
+// that is never committed. Encrypted puzzle inputs are found and decrypted by
+// `input::open` as if they were the puzzle input itself.
+//
+// An encrypted file is `MAGIC`, then the nonce, then the puzzle input encrypted
+// with ChaCha20-Poly1305 (which also detects the wrong key being used).
+
+// Environment variable holding the key, as 64 hex digits
+pub const KEY_VAR: &str = "AOC_VAULT_KEY";
+
+// Environment variable pointing at a file holding the key, as for `KEY_VAR`
+pub const KEY_FILE_VAR: &str = "AOC_VAULT_KEY_FILE";
+
+// The extension added to the name of an encrypted puzzle input
+pub const EXTENSION: &str = "enc";
+
+const MAGIC: &[u8] = b"aoc-vault 1\n";
+
+const NONCE_LEN: usize = 12;
+
+pub struct Key([u8; 32]);
+
+impl Key {
+    // A new key, at random
+    pub fn generate() -> Self {
+        Key(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        if s.len() != 64 || !s.is_ascii() {
+            return Err(format!(
+                "Expected a key of 64 hex digits, found {} characters",
+                s.chars().count()
+            ));
+        }
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(s.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).expect("Key is ASCII");
+            *byte = u8::from_str_radix(digits, 16)
+                .map_err(|_| format!("Cannot parse {digits:?} in the key as hex"))?;
+        }
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        Key::from_hex(&fs::read_to_string(path)?).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{error} in {}", path.display()),
+            )
+        })
+    }
+
+    // The key from `$AOC_VAULT_KEY`, otherwise from the file `$AOC_VAULT_KEY_FILE`
+    // points at, or `None` if neither is set
+    pub fn from_env() -> io::Result<Option<Self>> {
+        if let Ok(hex) = env::var(KEY_VAR) {
+            return Key::from_hex(&hex).map(Some).map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{error} in ${KEY_VAR}"),
+                )
+            });
+        }
+        match env::var_os(KEY_FILE_VAR) {
+            Some(path) => Key::from_file(Path::new(&path)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(&key.0.into())
+        .encrypt(&nonce, plaintext)
+        .expect("Puzzle inputs are small enough to encrypt");
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, encrypted: &[u8]) -> Result<Vec<u8>, String> {
+    let rest = encrypted
+        .strip_prefix(MAGIC)
+        .filter(|rest| rest.len() >= NONCE_LEN)
+        .ok_or("Not an encrypted puzzle input")?;
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    ChaCha20Poly1305::new(&key.0.into())
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Wrong key, or the file has been corrupted".to_string())
+}
+
+pub fn is_encrypted(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == EXTENSION)
+}
+
+// Where a file is kept once encrypted e.g. `input.txt.enc` for `input.txt`
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{EXTENSION}"));
+    PathBuf::from(name)
+}
+
+// Whether a file is encrypted, without a key to decrypt it with (e.g. so that
+// tests can skip the real puzzle inputs)
+pub fn is_locked(path: &Path) -> bool {
+    is_encrypted(path) && !matches!(Key::from_env(), Ok(Some(_)))
+}
+
+fn invalid_data(path: &Path, error: String) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Cannot decrypt {}: {error}", path.display()),
+    )
+}
+
+// Read and decrypt an encrypted file
+pub fn read(path: &Path, key: Option<&Key>) -> io::Result<Vec<u8>> {
+    let Some(key) = key else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "Cannot decrypt {} without a key: set ${KEY_VAR}, or ${KEY_FILE_VAR} to a file holding it",
+                path.display()
+            ),
+        ));
+    };
+    decrypt(key, &fs::read(path)?).map_err(|error| invalid_data(path, error))
+}
+
+// Encrypt a file alongside itself (e.g. `input.txt` as `input.txt.enc`),
+// returning where it was written
+pub fn encrypt_file(path: &Path, key: &Key) -> io::Result<PathBuf> {
+    let encrypted = encrypted_path(path);
+    fs::write(&encrypted, encrypt(key, &fs::read(path)?))?;
+    Ok(encrypted)
+}
+
+// Decrypt a file alongside itself (e.g. `input.txt.enc` as `input.txt`),
+// returning where it was written
+//
+// Note: a file already there (which may have been changed since it was
+// encrypted) is only overwritten if `force` is set
+pub fn decrypt_file(path: &Path, key: &Key, force: bool) -> io::Result<PathBuf> {
+    if !is_encrypted(path) {
+        return Err(invalid_data(
+            path,
+            format!("Expected a `.{EXTENSION}` file"),
+        ));
+    }
+    let decrypted = path.with_extension("");
+    if decrypted.exists() && !force {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "Cannot decrypt {} as {}, which already exists",
+                path.display(),
+                decrypted.display()
+            ),
+        ));
+    }
+    fs::write(&decrypted, read(path, Some(key))?)?;
+    Ok(decrypted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, b"30373\n25512\n");
+        assert!(encrypted.starts_with(MAGIC));
+        assert_ne!(encrypt(&key, b"30373\n25512\n"), encrypted);
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), b"30373\n25512\n");
+
+        assert!(decrypt(&Key::generate(), &encrypted).is_err());
+        let mut corrupted = encrypted.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&key, &corrupted).is_err());
+        assert!(decrypt(&key, b"30373\n25512\n").is_err());
+    }
+
+    #[test]
+    fn keys() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&key.to_hex()).unwrap().0, key.0);
+        assert_eq!(
+            Key::from_hex(&format!("{}\n", "0f".repeat(32))).unwrap().0,
+            [15; 32]
+        );
+        assert!(Key::from_hex("0f").is_err());
+        assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn files() {
+        let dir = env::temp_dir().join(format!("aoc-vault-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let key = Key::generate();
+        let path = dir.join("input.txt");
+        fs::write(&path, "noop\n").unwrap();
+        let encrypted = encrypt_file(&path, &key).unwrap();
+        assert_eq!(encrypted, dir.join("input.txt.enc"));
+        assert!(is_encrypted(&encrypted) && !is_encrypted(&path));
+
+        fs::remove_file(&path).unwrap();
+        assert!(read(&encrypted, None).is_err());
+        assert_eq!(decrypt_file(&encrypted, &key, false).unwrap(), path);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "noop\n");
+        assert!(decrypt_file(&path, &key, false).is_err());
+
+        fs::write(&path, "addx 3\n").unwrap();
+        let error = decrypt_file(&encrypted, &key, false).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "addx 3\n");
+        assert_eq!(decrypt_file(&encrypted, &key, true).unwrap(), path);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "noop\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,206 @@
+use crate::grid::{Coord, Grid};
+use crate::solution::Solution;
+use std::fmt::Write;
+
+// Visualisations of the days which are spatial (e.g. the rope in day 9, or the
+// sand in day 14), as a sequence of frames drawn while solving a part
+//
+// A frame is a grid of pixels, each with a character to draw it with in the
+// terminal and a colour to draw it with in an image. Days hand their frames to
+// `Frames` as they go, which passes them on to wherever they are being shown;
+// so a long simulation never has to keep all of its frames around.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pixel {
+    pub glyph: char,
+    pub colour: [u8; 3],
+}
+
+impl Pixel {
+    pub const fn new(glyph: char, colour: [u8; 3]) -> Self {
+        Pixel { glyph, colour }
+    }
+
+    // The brightness of the pixel, for greyscale images
+    pub fn grey(&self) -> u8 {
+        let [r, g, b] = self.colour.map(|c| c as u32);
+        ((299 * r + 587 * g + 114 * b) / 1000) as u8
+    }
+}
+
+pub type Frame = Grid<Pixel>;
+
+// The frame as text, one line per row
+pub fn ascii(frame: &Frame) -> String {
+    frame.render(|pixel| pixel.glyph)
+}
+
+// The frame as a binary PPM image, with each pixel drawn `scale` times as wide and tall
+pub fn ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    image(frame, scale, "P6", |pixel| pixel.colour.to_vec())
+}
+
+// The frame as a binary PGM (i.e. greyscale) image, as for `ppm`
+pub fn pgm(frame: &Frame, scale: usize) -> Vec<u8> {
+    image(frame, scale, "P5", |pixel| vec![pixel.grey()])
+}
+
+fn image(frame: &Frame, scale: usize, magic: &str, f: impl Fn(&Pixel) -> Vec<u8>) -> Vec<u8> {
+    let scale = scale.max(1);
+    let mut header = String::new();
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    writeln!(header, "{magic}\n{width} {height}\n255").expect("Cannot write to a string");
+    let mut bytes = header.into_bytes();
+    for row in frame.rows() {
+        let line = row
+            .iter()
+            .flat_map(|pixel| f(pixel).repeat(scale))
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            bytes.extend(&line);
+        }
+    }
+    bytes
+}
+
+// Where a day draws its frames to
+//
+// Only every `every`th step is drawn (and the final state always is), so that
+// long simulations can be sped up; frames are only drawn when they are needed.
+pub struct Frames<'a> {
+    every: usize,
+    steps: usize,
+    sink: &'a mut dyn FnMut(Frame),
+}
+
+impl<'a> Frames<'a> {
+    pub fn new(every: usize, sink: &'a mut dyn FnMut(Frame)) -> Self {
+        Frames {
+            every: every.max(1),
+            steps: 0,
+            sink,
+        }
+    }
+
+    // Draw the state after a step, if it is one of the steps being drawn
+    pub fn step(&mut self, draw: impl FnOnce() -> Frame) {
+        if self.steps.is_multiple_of(self.every) {
+            (self.sink)(draw());
+        }
+        self.steps += 1;
+    }
+
+    // Draw the final state
+    pub fn finish(&mut self, draw: impl FnOnce() -> Frame) {
+        (self.sink)(draw());
+    }
+}
+
+// Draw every frame of solving a part, or `None` if the day has nothing to
+// draw for it, for use in tests
+pub fn frames<S: Solution>(solution: &S, input: &S::Input, part: u8) -> Option<Vec<Frame>> {
+    let mut drawn = Vec::new();
+    let mut sink = |frame| drawn.push(frame);
+    solution
+        .visualise(input, part, &mut Frames::new(1, &mut sink))
+        .then_some(drawn)
+}
+
+// A frame for positions which may be negative, or too far apart to draw one
+// pixel each (e.g. day 15's sensors, millions of positions apart)
+//
+// The positions from `min` to `max` (in both coordinates) are drawn on at most
+// `limit` pixels wide and tall, with each pixel covering a square of positions.
+#[derive(Clone)]
+pub struct Canvas {
+    min: (i64, i64),
+    scale: i64, // The positions covered by a pixel, in each coordinate
+    pub frame: Frame,
+}
+
+impl Canvas {
+    pub fn new(min: (i64, i64), max: (i64, i64), limit: usize, background: Pixel) -> Self {
+        let span = (max.0 - min.0).max(max.1 - min.1) + 1;
+        let scale = (span + limit as i64 - 1) / limit.max(1) as i64;
+        let scale = scale.max(1);
+        let (width, height) = ((max.0 - min.0) / scale + 1, (max.1 - min.1) / scale + 1);
+        Canvas {
+            min,
+            scale,
+            frame: Grid::new(width as usize, height as usize, background),
+        }
+    }
+
+    // The pixel a position is drawn on, if it is on the canvas
+    pub fn pixel(&self, (x, y): (i64, i64)) -> Option<Coord> {
+        let (x, y) = (x - self.min.0, y - self.min.1);
+        if x < 0 || y < 0 {
+            return None;
+        }
+        let coord = Coord::new((x / self.scale) as usize, (y / self.scale) as usize);
+        self.frame.contains(coord).then_some(coord)
+    }
+
+    // The first position a pixel covers
+    pub fn position(&self, coord: Coord) -> (i64, i64) {
+        (
+            self.min.0 + coord.x as i64 * self.scale,
+            self.min.1 + coord.y as i64 * self.scale,
+        )
+    }
+
+    pub fn draw(&mut self, position: (i64, i64), pixel: Pixel) {
+        if let Some(coord) = self.pixel(position) {
+            self.frame[coord] = pixel;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOT: Pixel = Pixel::new('.', [0, 0, 0]);
+    const HASH: Pixel = Pixel::new('#', [255, 255, 255]);
+
+    #[test]
+    fn images() {
+        let mut frame = Grid::new(2, 1, DOT);
+        frame[Coord::new(1, 0)] = HASH;
+        assert_eq!(ascii(&frame), ".#\n");
+        assert_eq!(pgm(&frame, 1), b"P5\n2 1\n255\n\x00\xff");
+        let image = ppm(&frame, 2);
+        assert!(image.starts_with(b"P6\n4 2\n255\n"));
+        assert_eq!(image.len(), 11 + 4 * 2 * 3);
+        assert_eq!(
+            &image[11..23],
+            &[0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn frames() {
+        let mut drawn = Vec::new();
+        let mut sink = |frame: Frame| drawn.push(frame.width());
+        let mut frames = Frames::new(3, &mut sink);
+        for width in 1..=7 {
+            frames.step(|| Grid::new(width, 1, DOT));
+        }
+        frames.finish(|| Grid::new(8, 1, DOT));
+        assert_eq!(drawn, [1, 4, 7, 8]);
+    }
+
+    #[test]
+    fn canvas() {
+        let mut canvas = Canvas::new((-5, -5), (4, 4), 100, DOT);
+        assert_eq!((canvas.frame.width(), canvas.frame.height()), (10, 10));
+        canvas.draw((-5, 4), HASH);
+        canvas.draw((5, 0), HASH);
+        assert_eq!(canvas.frame[Coord::new(0, 9)], HASH);
+        assert_eq!(canvas.frame.iter().filter(|(_, &p)| p == HASH).count(), 1);
+
+        let canvas = Canvas::new((0, 0), (4000000, 1000), 100, DOT);
+        assert_eq!((canvas.frame.width(), canvas.frame.height()), (100, 1));
+        assert_eq!(canvas.pixel((3999999, 999)), Some(Coord::new(99, 0)));
+        assert_eq!(canvas.position(Coord::new(1, 0)), (40001, 0));
+    }
+}
@@ -0,0 +1,107 @@
+use common::generate::{Generated, Rng};
+use common::{parse, ParseError, Solution};
+
+// Handle the puzzle input so that groups of lines, separated by newlines,
+// are collected together within a vector of vectors
+//
+// e.g.
+//
+// "100\r\n200\r\n\r\n300\r\n"
+//
+// becomes:
+//
+// [[100, 200], [300]]
+//
+pub fn parse_groups(s: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse::groups(s)
+        .into_iter()
+        .map(|lines| {
+            lines
+                .into_iter()
+                .map(|line| line.parse::<i32>(line.text, "Unable to parse string as i32"))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+// Find the sum of calories each elf holds
+pub fn sums(elves: &[Vec<i32>]) -> Vec<i32> {
+    elves
+        .iter()
+        .map(|values| values.iter().sum())
+        .collect::<Vec<i32>>()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_groups(input)
+    }
+
+    // Find greatest calories any elf holds
+    fn part1(&self, elves: &Self::Input) -> i32 {
+        sums(elves)
+            .into_iter()
+            .max()
+            .expect("Cannot find greatest calories")
+    }
+
+    // Find the sum of the 3 greatest calories elves hold
+    //
+    // Finding the maximum is better for part 1, but
+    // sorting is better for part 2
+    fn part2(&self, elves: &Self::Input) -> i32 {
+        let mut sums = sums(elves);
+        sums.sort_by(|a, b| b.cmp(a));
+        sums.iter().take(3).sum::<i32>()
+    }
+
+    // `size` elves, sometimes with extra blank lines between them
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut input = String::new();
+        for elf in 0..size.max(1) {
+            if elf > 0 {
+                input.push_str(if rng.chance(0.1) { "\n\n" } else { "\n" });
+            }
+            for _ in 0..rng.between(1, 6) {
+                input.push_str(&format!("{}\n", rng.between(1, 60000)));
+            }
+        }
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day01.parse_file("test_input.txt");
+        assert_eq!(Day01.part1(&input), 24000);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day01.parse_file("test_input.txt");
+        assert_eq!(Day01.part2(&input), 45000);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day01);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day01, 0..20, &[0, 1, 10, 100]);
+    }
+}
@@ -0,0 +1,153 @@
+use common::generate::{Generated, Rng};
+use common::{parse, ParseError, Solution};
+
+pub type Round = (u8, u8);
+
+// Parse the strategy guide into rounds of (your shape, my shape)
+pub fn parse_rounds(s: &str) -> Result<Vec<Round>, ParseError> {
+    parse::lines(s)
+        .map(|line| {
+            let (you, me) = line.split_once(line.text, " ", "Cannot split line into shapes")?;
+            // Rust cannot index into a string; convert to bytes i.e. ASCII first
+            match (you.as_bytes(), me.as_bytes()) {
+                ([you @ b'A'..=b'C'], [me @ b'X'..=b'Z']) => Ok((*you, *me)),
+                ([b'A'..=b'C'], _) => Err(line.error(me, "Expected my shape to be X, Y or Z")),
+                _ => Err(line.error(you, "Expected your shape to be A, B or C")),
+            }
+        })
+        .collect()
+}
+
+// Given an outcome in the following format:
+//
+// 0 => Draw
+// 1 => Win
+// 2 => Lose
+//
+// transform it into the following score:
+//
+// Lose => 0
+// Draw => 3
+// Win  => 6
+//
+pub fn score(outcome: i32) -> i32 {
+    ((outcome + 1) % 3) * 3
+}
+
+// Calculate the total score for the rounds and using an interpretation function
+pub fn calculate_score(rounds: &[Round], f: fn(i32, i32) -> i32) -> i32 {
+    // Note that we find the difference from '@' for 'A', 'B', 'C', as
+    // '@' is the ASCII character before 'A'. This gives us:
+    //
+    // 'A' => 1
+    // 'B' => 2
+    // 'C' => 3
+    //
+    // Similarly, 'W' is the ASCII character before 'X', so finding the
+    // difference from that gives us:
+    //
+    // 'X' => 1
+    // 'Y' => 2
+    // 'Z' => 3
+    rounds
+        .iter()
+        .map(|(you, me)| f((you - b'@') as i32, (me - b'W') as i32))
+        .sum()
+}
+
+// Part 1 and Part 2 are actually very similar, with the only difference
+// being the interpretation functions used. However, even these two functions
+// are very similar. The idea is the following:
+//
+// 	(you, me) => outcome
+//
+// Your shape, along with my shape, will provide some kind of outcome.
+// This turns out to be mathematical:
+//
+// 	you + outcome = me     [in modulus 3]
+//
+// i.e. if you find the difference between your shape and my shape, and then
+// you add/subtract 3 to the difference until it is within the bounds [0, 3)
+// (i.e. modulus 3; [0, 1, 2]), then you will get the outcome.
+//
+// There are a few caveats:
+// - you must do `+ 3` in part_01 in order to take the *modulus*, rather
+//   than the *remainder* (which is what `% 3` does by itself)
+//
+// - you must do `+ 1` in part_02 in order to get the correct value of
+//   the shape (RPS should be 1,2,3 rather than 0,1,2)
+//
+// - you must do `- 2` in part_02 in order to get the correct value of
+//   the outcome (LDW should be -1,0,1 rather than 1,2,3)
+
+pub fn part_01(you: i32, me: i32) -> i32 {
+    me + score((me - you) % 3 + 3)
+}
+
+pub fn part_02(you: i32, outcome: i32) -> i32 {
+    (you + outcome) % 3 + 1 + score(outcome - 2)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<Round>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_rounds(input)
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> i32 {
+        calculate_score(rounds, part_01)
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> i32 {
+        calculate_score(rounds, part_02)
+    }
+
+    // `size` rounds
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let input = (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['A', 'B', 'C']),
+                    rng.choose(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect::<String>();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day02.parse_file("test_input.txt");
+        assert_eq!(Day02.part1(&input), 15);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day02.parse_file("test_input.txt");
+        assert_eq!(Day02.part2(&input), 12);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day02);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day02, 0..20, &[0, 1, 10, 100]);
+    }
+}
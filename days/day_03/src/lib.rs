@@ -0,0 +1,160 @@
+use common::generate::{Generated, Rng};
+use common::{parse, ParseError, Solution};
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+// Parse the puzzle input into a list of rucksacks
+pub fn parse_rucksacks(s: &str) -> Result<Vec<std::string::String>, ParseError> {
+    let rucksacks = parse::lines(s)
+        .map(|line| {
+            line.check_chars(|c| c.is_ascii_alphabetic(), "Expected an item (a-z or A-Z)")?;
+            if line.text.len() % 2 != 0 {
+                return Err(line.error(line.text, "Cannot split rucksack into two compartments"));
+            }
+            Ok(line.text.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if rucksacks.len() % 3 != 0 {
+        return Err(ParseError::end_of_input(
+            s,
+            "Cannot split rucksacks into groups of 3",
+        ));
+    }
+    Ok(rucksacks)
+}
+
+pub fn priority(c: u8) -> usize {
+    if c.is_ascii_lowercase() {
+        ((c - b'a') as usize) + 1
+    } else if c.is_ascii_uppercase() {
+        ((c - b'A') as usize) + 27
+    } else {
+        0
+    }
+}
+
+// `&a & &b` means to find the intersection between the HashSet `a`
+// and the HashSet `b`. Returns another HashSet.
+
+// Generate a group of three rucksacks, where only the badge is in all three
+// rucksacks, and only one item of each rucksack is in both its compartments
+//
+// Note: the other items are split between the three rucksacks (and then
+// between the two compartments of each rucksack), so they cannot be shared
+fn generate_group(rng: &mut Rng) -> Vec<String> {
+    let mut items = (b'a'..=b'z').chain(b'A'..=b'Z').collect::<Vec<_>>();
+    rng.shuffle(&mut items);
+    let badge = items.pop().expect("There are 52 items");
+    items
+        .chunks(items.len() / 3)
+        .map(|others| {
+            let mut others = others.to_vec();
+            // The item in both compartments, which may also be the badge
+            let shared = if rng.chance(0.2) {
+                badge
+            } else {
+                others.pop().expect("There are 17 items for each rucksack")
+            };
+            let (left, right) = others.split_at(others.len() / 2);
+            let length = rng.between(2, 16) as usize;
+            let mut compartments = [left, right].map(|pool| {
+                let mut compartment = vec![shared];
+                compartment.extend((1..length).map(|_| *rng.choose(pool)));
+                compartment
+            });
+            if shared != badge {
+                compartments[rng.below(2)][1 + rng.below(length - 1)] = badge;
+            }
+            for compartment in &mut compartments {
+                rng.shuffle(compartment);
+            }
+            String::from_utf8(compartments.concat()).expect("Items are ASCII")
+        })
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_rucksacks(input)
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> usize {
+        rucksacks
+            .iter()
+            .map(|line| {
+                let (bytes, m) = (line.as_bytes(), line.len() / 2);
+                let a = HashSet::<u8>::from_iter(bytes[..m].iter().cloned());
+                let b = HashSet::<u8>::from_iter(bytes[m..].iter().cloned());
+                (&a & &b)
+                    .drain()
+                    .next()
+                    .expect("No elements in intersection")
+            })
+            .map(priority)
+            .sum()
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> usize {
+        rucksacks
+            .chunks(3)
+            .map(|group| {
+                let [a, b, c] = group else {
+                    panic!("Cannot split rucksacks into groups of 3")
+                };
+                let a = HashSet::<u8>::from_iter(a.as_bytes().iter().cloned());
+                let b = HashSet::<u8>::from_iter(b.as_bytes().iter().cloned());
+                let c = HashSet::<u8>::from_iter(c.as_bytes().iter().cloned());
+                (&(&a & &b) & &c)
+                    .drain()
+                    .next()
+                    .expect("No elements in intersection")
+            })
+            .map(priority)
+            .sum()
+    }
+
+    // `size` groups of three rucksacks
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let input = (0..size.max(1))
+            .flat_map(|_| generate_group(rng))
+            .map(|rucksack| rucksack + "\n")
+            .collect::<String>();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day03.parse_file("test_input.txt");
+        assert_eq!(Day03.part1(&input), 157);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day03.parse_file("test_input.txt");
+        assert_eq!(Day03.part2(&input), 70);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day03);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day03, 0..20, &[0, 1, 10, 100]);
+    }
+}
@@ -0,0 +1,123 @@
+use common::generate::{Generated, Rng};
+use common::{parse, Line, ParseError, Solution};
+
+pub type Section = (u32, u32);
+
+pub type Pair = (Section, Section);
+
+// Parse a section, e.g. "2-4"
+fn parse_section(line: &Line, section: &str, name: &str) -> Result<Section, ParseError> {
+    let (x, y) = line.split_once(
+        section,
+        "-",
+        &format!("Cannot split {name} section into pair"),
+    )?;
+    let x = line.parse::<u32>(x, &format!("Cannot parse first value of {name} section"))?;
+    let y = line.parse::<u32>(y, &format!("Cannot parse second value of {name} section"))?;
+    if x > y {
+        return Err(line.error(
+            section,
+            &format!("The {name} section ends before it starts"),
+        ));
+    }
+    Ok((x, y))
+}
+
+// Parse the puzzle input into pairs of sections
+pub fn parse_pairs(s: &str) -> Result<Vec<Pair>, ParseError> {
+    parse::lines(s)
+        .map(|line| {
+            let (first, second) = line.split_once(line.text, ",", "Cannot split line into pair")?;
+            Ok((
+                parse_section(&line, first, "first")?,
+                parse_section(&line, second, "second")?,
+            ))
+        })
+        .collect()
+}
+
+pub fn count_overlaps(pairs: &[Pair], f: fn(Pair) -> bool) -> usize {
+    pairs
+        .iter()
+        .filter(|((x0, y0), (x1, y1))| f(((*x0, *y0), (*x1, *y1))))
+        .count()
+}
+
+// Note that for any section `(x, y)` that `x <= y` always holds true
+
+// Find overlaps where one section completely contains the other section
+// ... which occurs when one section has its boundaries fully inside the others
+pub fn part_01(((x0, y0), (x1, y1)): Pair) -> bool {
+    (x0 <= x1 && y1 <= y0) || (x1 <= x0 && y0 <= y1)
+}
+
+// Find overlaps where any part of the pair of sections overlap
+// ... which is the opposite of finding pairs which *do not* overlap
+pub fn part_02(((x0, y0), (x1, y1)): Pair) -> bool {
+    !(y0 < x1 || y1 < x0)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_pairs(input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> usize {
+        count_overlaps(pairs, part_01)
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> usize {
+        count_overlaps(pairs, part_02)
+    }
+
+    // `size` pairs of sections
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut section = || {
+            let x = rng.between(1, 99);
+            (x, rng.between(x, 99))
+        };
+        let input = (0..size.max(1))
+            .map(|_| {
+                let ((x0, y0), (x1, y1)) = (section(), section());
+                format!("{x0}-{y0},{x1}-{y1}\n")
+            })
+            .collect::<String>();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day04.parse_file("test_input.txt");
+        assert_eq!(Day04.part1(&input), 2);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day04.parse_file("test_input.txt");
+        assert_eq!(Day04.part2(&input), 4);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day04);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day04, 0..20, &[0, 1, 10, 100]);
+    }
+}
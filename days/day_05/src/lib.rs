@@ -0,0 +1,283 @@
+use common::generate::{Generated, Rng};
+use common::simulation::{Driver, Simulation};
+use common::{parse, Line, ParseError, Solution};
+
+pub type Stack = Vec<u8>;
+
+pub type Procedure = (usize, usize, usize);
+
+// Parse the drawing of the stacks, where the last line numbers the stacks
+//
+// e.g.
+//
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+//
+// Note: the numbers are only split by whitespace, as from stack 100 onwards
+// they no longer fit beneath their stack
+fn parse_stacks(lines: &[Line]) -> Result<Vec<Stack>, ParseError> {
+    let (footer, drawing) = lines.split_last().expect("Groups are never empty");
+    for (index, number) in footer.text.split_whitespace().enumerate() {
+        if footer.parse::<usize>(number, "Cannot parse stack number")? != index + 1 {
+            return Err(footer.error(number, &format!("Expected stack {}", index + 1)));
+        }
+    }
+    let mut stacks: Vec<Stack> = vec![Stack::new(); footer.text.split_whitespace().count()];
+    // Start from the end i.e. the start of the stack, so that we `.push()` items to the back of the stack
+    for line in drawing.iter().rev() {
+        line.check_chars(
+            |c| c.is_ascii(),
+            "Expected only ASCII in the drawing of the stacks",
+        )?;
+        for (index, bytes) in line.text.as_bytes().chunks(4).enumerate() {
+            let start = index * 4;
+            let end = (start + 3).min(line.text.len());
+            // Ignore empty areas in the stack
+            match bytes {
+                [b' ', ..] => {}
+                [b'[', c, b']', ..] if c.is_ascii_uppercase() => match stacks.get_mut(index) {
+                    Some(stack) => stack.push(*c),
+                    None => {
+                        return Err(line.error(
+                            &line.text[start..end],
+                            "There is no stack beneath this crate",
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(line.error(&line.text[start..end], "Expected a crate such as [A]"));
+                }
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+fn parse_procedures(lines: &[Line], stacks: usize) -> Result<Vec<Procedure>, ParseError> {
+    let parse_stack = |line: &Line, word: &str, message: &str| {
+        let stack = line.parse::<usize>(word, message)?;
+        if (1..=stacks).contains(&stack) {
+            Ok(stack)
+        } else {
+            Err(line.error(word, &format!("There is no stack {stack}")))
+        }
+    };
+    lines
+        .iter()
+        .map(|line| match line.text.split(' ').collect::<Vec<_>>()[..] {
+            ["move", move_amount, "from", from, "to", to] => Ok((
+                line.parse::<usize>(move_amount, "Cannot parse move amount")?,
+                parse_stack(line, from, "Cannot parse from stack")?,
+                parse_stack(line, to, "Cannot parse to stack")?,
+            )),
+            _ => Err(line.error(
+                line.text,
+                "Expected a procedure such as \"move 1 from 2 to 1\"",
+            )),
+        })
+        .collect()
+}
+
+// Parse the puzzle input into the starting stacks, and the rearrangement procedures
+pub fn parse_drawing(s: &str) -> Result<(Vec<Stack>, Vec<Procedure>), ParseError> {
+    match &parse::groups(s)[..] {
+        [stacks, procedures] => {
+            let stacks = parse_stacks(stacks)?;
+            let procedures = parse_procedures(procedures, stacks.len())?;
+            Ok((stacks, procedures))
+        }
+        [_, _, extra, ..] => {
+            Err(extra[0].error(extra[0].text, "Expected only stacks and procedures"))
+        }
+        _ => Err(ParseError::end_of_input(
+            s,
+            "Cannot split input into stacks and procedures",
+        )),
+    }
+}
+
+// The crane carrying out the rearrangement procedures, one procedure per step
+struct Crane<'a> {
+    stacks: Vec<Stack>,
+    procedures: &'a [Procedure],
+    done: usize,                       // The procedures carried out so far
+    f: fn(&Stack, usize, usize) -> u8, // Which crate is moved by each move of a procedure
+}
+
+impl Simulation for Crane<'_> {
+    type Snapshot = Vec<Stack>;
+
+    fn step(&mut self) {
+        let (move_amount, from, to) = self.procedures[self.done];
+        let stacks = &mut self.stacks;
+        let from_stack = stacks[from - 1].clone();
+        let to_stack = &mut (stacks[to - 1]);
+        // First push the items to the new stack
+        for index in 0..move_amount {
+            to_stack.push((self.f)(&from_stack, move_amount, index));
+        }
+        // Then pop the items from the old stack
+        let from_stack = &mut (stacks[from - 1]);
+        from_stack.drain(from_stack.len() - move_amount..);
+        self.done += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.done == self.procedures.len()
+    }
+
+    fn snapshot(&self) -> Vec<Stack> {
+        self.stacks.clone()
+    }
+}
+
+pub fn game_theory(
+    (stacks, procedures): &(Vec<Stack>, Vec<Procedure>),
+    f: fn(&Stack, usize, usize) -> u8,
+) -> String {
+    let mut crane = Crane {
+        stacks: stacks.clone(),
+        procedures,
+        done: 0,
+        f,
+    };
+    Driver::new().run(&mut crane);
+    crane
+        .stacks
+        .iter()
+        .map(|stack| *stack.last().expect("No crate on the top of this stack") as char)
+        .collect::<String>()
+}
+
+pub fn part_01(stack: &Stack, _move_amount: usize, index: usize) -> u8 {
+    stack[stack.len() - index - 1]
+}
+
+pub fn part_02(stack: &Stack, move_amount: usize, index: usize) -> u8 {
+    stack[stack.len() - move_amount + index]
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = (Vec<Stack>, Vec<Procedure>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_drawing(input)
+    }
+
+    fn part1(&self, drawing: &Self::Input) -> String {
+        game_theory(drawing, part_01)
+    }
+
+    fn part2(&self, drawing: &Self::Input) -> String {
+        game_theory(drawing, part_02)
+    }
+
+    // `size` stacks, and five times as many procedures
+    //
+    // Note: a procedure never empties the stack it moves from, so that there is
+    // always a crate on the top of every stack at the end. There are more
+    // crates than stacks, so there is always a stack to move from.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let count = size.max(2);
+        let mut stacks = (0..count)
+            .map(|index| {
+                (0..rng.between(if index == 0 { 2 } else { 1 }, 8))
+                    .map(|_| b'A' + rng.below(26) as u8)
+                    .collect::<Stack>()
+            })
+            .collect::<Vec<_>>();
+
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut input = String::new();
+        for level in (0..height).rev() {
+            let line = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(&c) => format!("[{}]", c as char),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            input.push_str(&format!("{line}\n"));
+        }
+        let footer = (1..=count)
+            .map(|number| format!("{number:^3}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        input.push_str(&format!("{footer}\n\n"));
+
+        for _ in 0..5 * count {
+            let from = *rng.choose(
+                &(0..count)
+                    .filter(|&index| stacks[index].len() > 1)
+                    .collect::<Vec<_>>(),
+            );
+            let to = (from + 1 + rng.below(count - 1)) % count;
+            let move_amount = rng.between(1, stacks[from].len() as i64 - 1) as usize;
+            let at = stacks[from].len() - move_amount;
+            let moved = stacks[from].split_off(at);
+            stacks[to].extend(moved);
+            input.push_str(&format!(
+                "move {move_amount} from {} to {}\n",
+                from + 1,
+                to + 1
+            ));
+        }
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::simulation::Stop;
+
+    #[test]
+    fn part1() {
+        let input = Day05.parse_file("test_input.txt");
+        assert_eq!(Day05.part1(&input), "CMZ");
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day05.parse_file("test_input.txt");
+        assert_eq!(Day05.part2(&input), "MCD");
+    }
+
+    #[test]
+    fn crane() {
+        let (stacks, procedures) = Day05.parse_file("test_input.txt");
+        let mut crane = Crane {
+            stacks,
+            procedures: &procedures,
+            done: 0,
+            f: part_01,
+        };
+        let run = Driver::new().max_steps(1).checkpoints(1).run(&mut crane);
+        assert_eq!(run.stop, Stop::MaxSteps);
+        assert_eq!(
+            run.checkpoints,
+            [(1, vec![b"ZND".to_vec(), b"MC".to_vec(), b"P".to_vec()])]
+        );
+        assert_eq!(Driver::new().run(&mut crane).steps, 3);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day05);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day05, 0..20, &[0, 1, 10, 100, 150]);
+    }
+}
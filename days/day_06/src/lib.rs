@@ -0,0 +1,137 @@
+use common::generate::{Generated, Rng};
+use common::{config, parse, ParseError, Solution};
+
+// Parse the puzzle input into the datastream buffer
+pub fn parse_datastream(s: &str) -> Result<String, ParseError> {
+    parse::lines(s)
+        .map(|line| {
+            line.check_chars(|c| c.is_ascii_lowercase(), "Expected a character from a-z")?;
+            Ok(line.text)
+        })
+        .collect()
+}
+
+fn index_of(c: &u8) -> usize {
+    (c - b'a') as usize
+}
+
+pub fn find_marker(datastream: &str, window_size: usize) -> usize {
+    let bytes = datastream.as_bytes();
+    let window_size = window_size - 1;
+    let mut histogram = [0; 26];
+    for (_, c) in bytes.iter().enumerate().take(window_size) {
+        histogram[index_of(c)] += 1;
+    }
+    for (index, c) in bytes.iter().enumerate().skip(window_size) {
+        histogram[index_of(c)] += 1;
+        if !histogram.iter().any(|x| *x > 1) {
+            return index + 1;
+        }
+        histogram[index_of(&bytes[index - window_size])] -= 1;
+    }
+    0
+}
+
+pub struct Day06 {
+    pub windows: (usize, usize), // Number of distinct characters in each marker for part 1 and part 2
+}
+
+impl Default for Day06 {
+    fn default() -> Self {
+        Day06 { windows: (4, 14) }
+    }
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_datastream(input)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "windows" => self.windows = config::pair(name, value)?,
+            _ => return Err(format!("Day 6 has no parameter {name:?}")),
+        }
+        if self.windows.0 == 0 || self.windows.1 == 0 {
+            return Err("Expected windows of at least 1 character".to_string());
+        }
+        Ok(())
+    }
+
+    // Find the start-of-packet marker
+    fn part1(&self, datastream: &Self::Input) -> usize {
+        find_marker(datastream, self.windows.0)
+    }
+
+    // Find the start-of-message marker
+    fn part2(&self, datastream: &Self::Input) -> usize {
+        find_marker(datastream, self.windows.1)
+    }
+
+    // A datastream `size` characters long, where the only markers are within
+    // one run of distinct characters
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let (smallest, largest) = (
+            self.windows.0.min(self.windows.1),
+            self.windows.0.max(self.windows.1),
+        );
+        if largest > 26 {
+            return None;
+        }
+        // Too few different characters to ever form a marker
+        let filler = (smallest - 1).max(1);
+        let mut datastream = (0..size.max(largest))
+            .map(|_| b'a' + rng.below(filler) as u8)
+            .collect::<Vec<_>>();
+        let mut run = (b'a'..=b'z').collect::<Vec<_>>();
+        rng.shuffle(&mut run);
+        let start = rng.below(datastream.len() - largest + 1);
+        datastream[start..start + largest].copy_from_slice(&run[..largest]);
+        let input = String::from_utf8(datastream).expect("Datastream is ASCII") + "\n";
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [&str; 5] = [
+        "test_input_0.txt",
+        "test_input_1.txt",
+        "test_input_2.txt",
+        "test_input_3.txt",
+        "test_input_4.txt",
+    ];
+
+    #[test]
+    fn part1() {
+        let markers =
+            EXAMPLES.map(|name| Day06::default().part1(&Day06::default().parse_file(name)));
+        assert_eq!(markers, [7, 5, 6, 10, 11]);
+    }
+
+    #[test]
+    fn part2() {
+        let markers =
+            EXAMPLES.map(|name| Day06::default().part2(&Day06::default().parse_file(name)));
+        assert_eq!(markers, [19, 23, 23, 29, 26]);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(Day06::default);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(Day06::default, 0..20, &[0, 1, 10, 100]);
+    }
+}
@@ -0,0 +1,301 @@
+use common::generate::{Generated, Rng};
+use common::repl::{self, Repl};
+use common::{config, parse, ParseError, Solution};
+use std::collections::BTreeMap;
+
+pub enum IO {
+    CD(String), // The directory changed to, which may be ".."
+    File(u32),
+}
+
+// Parse the puzzle input into the terminal output we care about
+pub fn parse_terminal(s: &str) -> Result<Vec<IO>, ParseError> {
+    parse::lines(s)
+        .filter_map(|line| match line.text.split(' ').collect::<Vec<_>>()[..] {
+            // We do not need "$ ls" or "dir X" for the puzzle
+            ["$", "ls"] | ["dir", _] => None,
+            ["$", "cd", directory] => Some(Ok(IO::CD(directory.to_string()))),
+            [size, _] => Some(
+                line.parse::<u32>(size, "Cannot parse file size as u32")
+                    .map(IO::File),
+            ),
+            _ => Some(Err(
+                line.error(line.text, "Expected a command, directory or file")
+            )),
+        })
+        .collect()
+}
+
+// Traverse through the iterator as though it were a directory-file tree
+pub fn traverse<F>(iter: &mut std::slice::Iter<'_, IO>, f: &mut F) -> u32
+where
+    F: FnMut(u32),
+{
+    match iter.next() {
+        // "$ cd X" where "X" is *not* ".."
+        Some(IO::CD(directory)) if directory != ".." => {
+            // Traverse through the current working directory until we stop
+            let sum = traverse(iter, f);
+            f(sum);
+            sum + traverse(iter, f)
+        }
+        // "123 abc.txt"
+        Some(IO::File(size)) => size + traverse(iter, f),
+        // Everything else stops traversing through the current working directory
+        _ => 0,
+    }
+}
+
+// The size of every directory, by its path e.g. `/a/e`
+pub fn directory_sizes(lines: &[IO]) -> BTreeMap<String, u32> {
+    let mut sizes = BTreeMap::from([("/".to_string(), 0)]);
+    let mut path = Vec::new();
+    for line in lines {
+        match line {
+            IO::CD(directory) if directory == "/" => path.clear(),
+            IO::CD(directory) if directory == ".." => {
+                path.pop();
+            }
+            IO::CD(directory) => {
+                path.push(directory.as_str());
+                sizes.entry(format!("/{}", path.join("/"))).or_insert(0);
+            }
+            // The file counts towards every directory it is within
+            IO::File(size) => {
+                for depth in 0..=path.len() {
+                    *sizes
+                        .entry(format!("/{}", path[..depth].join("/")))
+                        .or_insert(0) += size;
+                }
+            }
+        }
+    }
+    sizes
+}
+
+// Commands to look around the filesystem in `aoc repl`
+struct Filesystem {
+    sizes: BTreeMap<String, u32>,
+}
+
+impl Repl for Filesystem {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        vec![(
+            "dir <path>",
+            "The size of a directory (e.g. /a/e), and of each directory directly within it",
+        )]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "dir" => {
+                let path = repl::arg::<String>(args, 0, "path")?;
+                let path = format!("/{}", path.trim_matches('/'));
+                let size = self
+                    .sizes
+                    .get(&path)
+                    .ok_or_else(|| format!("Cannot find directory {path}"))?;
+                let prefix = format!("{}/", path.trim_end_matches('/'));
+                let within = self.sizes.iter().filter(|(directory, _)| {
+                    directory
+                        .strip_prefix(&prefix)
+                        .is_some_and(|rest| !rest.is_empty() && !rest.contains('/'))
+                });
+                Ok(std::iter::once((&path, size))
+                    .chain(within)
+                    .map(|(directory, size)| format!("{size:>10}  {directory}\n"))
+                    .collect())
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+// Generate the output of `ls` for a directory, given its subdirectories,
+// taking the space its files use from `free`
+fn generate_listing(rng: &mut Rng, children: &[usize], largest: i64, free: &mut i64) -> String {
+    let mut entries = children
+        .iter()
+        .map(|child| format!("dir d{child}"))
+        .collect::<Vec<_>>();
+    for file in 0..rng.below(4) {
+        let size = rng.between(1, largest).min(*free);
+        if size == 0 {
+            break;
+        }
+        *free -= size;
+        entries.push(format!("{size} f{file}.txt"));
+    }
+    rng.shuffle(&mut entries);
+    let listing = entries
+        .into_iter()
+        .map(|entry| entry + "\n")
+        .collect::<String>();
+    format!("$ ls\n{listing}")
+}
+
+pub struct Day07 {
+    pub disk_budget: u32, // The most space that can be used while still able to update
+}
+
+impl Default for Day07 {
+    fn default() -> Self {
+        // 70000000 total, of which 30000000 must be free for the update
+        Day07 {
+            disk_budget: 40000000,
+        }
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<IO>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_terminal(input)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "disk_budget" => self.disk_budget = config::value(name, value)?,
+            _ => return Err(format!("Day 7 has no parameter {name:?}")),
+        }
+        Ok(())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> u32 {
+        let mut part_01 = 0;
+        traverse(&mut lines.iter(), &mut |sum| {
+            if sum <= 100000 {
+                part_01 += sum;
+            }
+        });
+        part_01
+    }
+
+    fn part2(&self, lines: &Self::Input) -> u32 {
+        let used_space = traverse(&mut lines.iter(), &mut |_| {});
+        let needed_space = used_space.saturating_sub(self.disk_budget);
+        let mut part_02 = used_space;
+        traverse(&mut lines.iter(), &mut |sum| {
+            if needed_space <= sum && sum < part_02 {
+                part_02 = sum;
+            }
+        });
+        part_02
+    }
+
+    fn repl<'a>(&'a self, lines: &'a Self::Input) -> Option<Box<dyn Repl + 'a>> {
+        Some(Box::new(Filesystem {
+            sizes: directory_sizes(lines),
+        }))
+    }
+
+    // A filesystem of `size` directories, which are often deeply nested
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let count = size.max(1);
+        // Each directory is usually inside the directory before it
+        let mut children = vec![Vec::new(); count];
+        for directory in 1..count {
+            let parent = if rng.chance(0.5) {
+                directory - 1
+            } else {
+                rng.below(directory)
+            };
+            children[parent].push(directory);
+        }
+        // Files are either small, so that many directories count towards part
+        // 1, or sized against the whole 70000000 disk, so that it is often more
+        // than `disk_budget` full and part 2 has a directory to find. No file is
+        // bigger than the space left, so the filesystem always fits on the disk.
+        let largest = if rng.chance(0.5) {
+            (70000000 / (3 * count) as i64).min(300000)
+        } else {
+            70000000 / count as i64
+        };
+        let mut free = 70000000;
+
+        let mut input = String::from("$ cd /\n");
+        input.push_str(&generate_listing(rng, &children[0], largest, &mut free));
+        let mut path = vec![(0, 0)];
+        while let Some((directory, next)) = path.last_mut() {
+            match children[*directory].get(*next) {
+                Some(&child) => {
+                    *next += 1;
+                    input.push_str(&format!("$ cd d{child}\n"));
+                    input.push_str(&generate_listing(rng, &children[child], largest, &mut free));
+                    path.push((child, 0));
+                }
+                None => {
+                    path.pop();
+                    if !path.is_empty() {
+                        input.push_str("$ cd ..\n");
+                    }
+                }
+            }
+        }
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day07::default().parse_file("test_input.txt");
+        assert_eq!(Day07::default().part1(&input), 95437);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day07::default().parse_file("test_input.txt");
+        assert_eq!(Day07::default().part2(&input), 24933642);
+    }
+
+    #[test]
+    fn repl() {
+        let day = Day07::default();
+        let input = day.parse_file("test_input.txt");
+        let mut repl = day.repl(&input).unwrap();
+        assert_eq!(
+            repl.run("dir", &["/"]).unwrap(),
+            "  48381165  /\n     94853  /a\n  24933642  /d\n"
+        );
+        assert_eq!(repl.run("dir", &["/a/e/"]).unwrap(), "       584  /a/e\n");
+        assert!(repl.run("dir", &["/b.txt"]).is_err());
+        assert!(repl.run("cd", &["/"]).is_err());
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(Day07::default);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(Day07::default, 0..20, &[0, 1, 10, 100]);
+    }
+
+    #[test]
+    fn generate_full_disk() {
+        // Part 2 only has to search for a directory to delete once more than
+        // `disk_budget` is used, which must happen for some seeds
+        let day = Day07::default();
+        let full = (0..20)
+            .filter(|&seed| {
+                let generated = day.generate(&mut Rng::new(seed), 10).unwrap();
+                let input = day.parse(&generated.input).unwrap();
+                let used = directory_sizes(&input)["/"];
+                assert!(used <= 70000000, "Seed {seed} used {used}");
+                used > day.disk_budget && day.part2(&input) >= used - day.disk_budget
+            })
+            .count();
+        assert!(full > 0);
+    }
+}
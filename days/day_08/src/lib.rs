@@ -0,0 +1,276 @@
+use common::differential::Alternative;
+use common::generate::{Generated, Rng};
+use common::{Coord, Direction, Grid, ParseError, Solution};
+use itertools::Itertools; // for `.cartesian_product`
+
+#[derive(Clone)]
+pub struct Tree {
+    pub height: usize,
+    pub north: Coord, // Furthest tree north
+    pub east: Coord,  // Furthest tree east
+    pub south: Coord, // Furthest tree south
+    pub west: Coord,  // Furthest tree west
+}
+
+// Parse the puzzle input into a grid of trees, where
+// each tree can initially only see itself
+pub fn parse_trees(s: &str) -> Result<Grid<Tree>, ParseError> {
+    let heights = Grid::parse(s, |c| c.to_digit(10), "Expected a tree height from 0-9")?;
+    let mut trees = Grid::new(
+        heights.width(),
+        heights.height(),
+        Tree {
+            height: 0,
+            north: Coord::default(),
+            east: Coord::default(),
+            south: Coord::default(),
+            west: Coord::default(),
+        },
+    );
+    for (tree_xy, height) in heights.iter() {
+        trees[tree_xy] = Tree {
+            height: *height as usize,
+            north: tree_xy,
+            east: tree_xy,
+            south: tree_xy,
+            west: tree_xy,
+        };
+    }
+    Ok(trees)
+}
+
+// Given a tree and a function `f` that represents a certain direction,
+// find the furthest tree that tree can see in that direction
+//
+// By finding the furthest tree, we can solve both part 1 and part 2, as:
+// - that tree must be taller than the furthest tree it can see (part 1)
+// - the distance between that tree and the furthest tree it can see (part 2)
+//
+// To find the furthest tree a tree can see, we essentially store a linked list
+// intertwined within the `Tree` type. It goes back to see the furthest tree for
+// other trees in that direction, and finds the smallest one of these.
+//
+// There are better ways to solve part 1, which do not involve the linked list
+// (instead computing the _largest_ tree in a certain direction). And for part 2,
+// you can completely brute force it and check all four directions for any given tree.
+// However, this solution is the only one that seems to unite the two parts with a
+// common function.
+fn furthest_tree(
+    trees: &Grid<Tree>,
+    mut from_xy: Coord,
+    to_xy: Coord,
+    f: fn(&Tree) -> Coord,
+) -> Coord {
+    while trees[from_xy].height < trees[to_xy].height {
+        let far_tree_xy = f(&trees[from_xy]);
+        if far_tree_xy == from_xy {
+            return from_xy;
+        }
+        from_xy = far_tree_xy;
+    }
+    from_xy
+}
+
+// A tree is visible from_xy the edges of the grid if:
+// - that tree is taller than the furthest tree that tree can see
+// - that tree is the same tree as the furthest tree that tree can see
+//
+// Trees on the edge have themselves as the furthest tree, so they are *visible*.
+pub fn is_visible(trees: &Grid<Tree>, tree_xy: Coord) -> bool {
+    let tree = &trees[tree_xy];
+    [tree.north, tree.east, tree.south, tree.west]
+        .into_iter()
+        .any(|far_tree_xy| tree.height > trees[far_tree_xy].height || far_tree_xy == tree_xy)
+}
+
+// A tree calculates its scenic score by doing the following:
+// - find the furthest trees that tree can see in all four directions,
+//   find the distances between them and that tree, and then find the
+//   product
+//
+// Trees on the edge have themselves as the furthest tree, so their scenic score becomes *0*.
+pub fn scenic_score(trees: &Grid<Tree>, tree_xy: Coord) -> usize {
+    let tree = &trees[tree_xy];
+    [tree.north, tree.east, tree.south, tree.west]
+        .into_iter()
+        .map(|far_tree_xy| tree_xy.manhattan(far_tree_xy))
+        .product()
+}
+
+// Find the furthest tree each tree can see, in all four directions
+//
+// Note: columns are surveyed from west to east (and rows from north to south
+// within each column), so that the trees in the previous row/column have
+// always been surveyed already
+pub fn survey(trees: &Grid<Tree>) -> Grid<Tree> {
+    let mut trees = trees.clone();
+    let (width, height) = (trees.width(), trees.height());
+
+    for (x, y) in (1..width.saturating_sub(1)).cartesian_product(1..height.saturating_sub(1)) {
+        let (a, b) = (width - x - 1, height - y - 1);
+        let xy = |x, y| Coord::new(x, y);
+        trees[xy(x, y)].north = furthest_tree(&trees, xy(x, y - 1), xy(x, y), |tree| tree.north);
+        trees[xy(a, y)].east = furthest_tree(&trees, xy(a + 1, y), xy(a, y), |tree| tree.east);
+        trees[xy(a, b)].south = furthest_tree(&trees, xy(a, b + 1), xy(a, b), |tree| tree.south);
+        trees[xy(x, y)].west = furthest_tree(&trees, xy(x - 1, y), xy(x, y), |tree| tree.west);
+    }
+
+    trees
+}
+
+// The better way to solve part 1: sweep along every row and column from both
+// ends, keeping the tallest tree so far. Any tree taller than that is visible.
+fn mark_visible(trees: &Grid<Tree>, visible: &mut Grid<bool>, line: impl Iterator<Item = Coord>) {
+    let mut tallest = None;
+    for tree_xy in line {
+        if tallest < Some(trees[tree_xy].height) {
+            visible[tree_xy] = true;
+            tallest = Some(trees[tree_xy].height);
+        }
+    }
+}
+
+pub fn count_visible_by_tallest(trees: &Grid<Tree>) -> usize {
+    let (width, height) = (trees.width(), trees.height());
+    let mut visible = Grid::new(width, height, false);
+    for y in 0..height {
+        mark_visible(trees, &mut visible, (0..width).map(|x| Coord::new(x, y)));
+        mark_visible(
+            trees,
+            &mut visible,
+            (0..width).rev().map(|x| Coord::new(x, y)),
+        );
+    }
+    for x in 0..width {
+        mark_visible(trees, &mut visible, (0..height).map(|y| Coord::new(x, y)));
+        mark_visible(
+            trees,
+            &mut visible,
+            (0..height).rev().map(|y| Coord::new(x, y)),
+        );
+    }
+    visible.iter().filter(|(_, &visible)| visible).count()
+}
+
+// The brute force way to solve part 2: look from every tree in all four
+// directions, until a tree at least as tall blocks the view
+pub fn viewing_distance(trees: &Grid<Tree>, tree_xy: Coord, direction: Direction) -> usize {
+    let height = trees[tree_xy].height;
+    let mut distance = 0;
+    for far_tree_xy in trees.ray(tree_xy, direction) {
+        distance += 1;
+        if trees[far_tree_xy].height >= height {
+            break;
+        }
+    }
+    distance
+}
+
+pub fn most_scenic_by_brute_force(trees: &Grid<Tree>) -> usize {
+    trees
+        .coords()
+        .map(|tree_xy| {
+            Direction::ORTHOGONAL
+                .into_iter()
+                .map(|direction| viewing_distance(trees, tree_xy, direction))
+                .product::<usize>()
+        })
+        .max()
+        .expect("Cannot retrieve max")
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Grid<Tree>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_trees(input)
+    }
+
+    // Count the visible trees
+    fn part1(&self, trees: &Self::Input) -> usize {
+        let trees = survey(trees);
+        trees
+            .coords()
+            .filter(|&tree_xy| is_visible(&trees, tree_xy))
+            .count()
+    }
+
+    // Find the most scenic score
+    fn part2(&self, trees: &Self::Input) -> usize {
+        let trees = survey(trees);
+        trees
+            .coords()
+            .map(|tree_xy| scenic_score(&trees, tree_xy))
+            .max()
+            .expect("Cannot retrieve max")
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        vec![
+            Alternative {
+                name: "tallest",
+                part: 1,
+                solve: |_, trees| count_visible_by_tallest(trees).to_string(),
+            },
+            Alternative {
+                name: "brute force",
+                part: 2,
+                solve: |_, trees| most_scenic_by_brute_force(trees).to_string(),
+            },
+        ]
+    }
+
+    // A forest `size` trees wide, and up to `size` trees tall
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let width = size.max(1);
+        let height = 1 + rng.below(width);
+        let input = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| char::from(b'0' + rng.below(10) as u8))
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect::<String>();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day08.parse_file("test_input.txt");
+        assert_eq!(Day08.part1(&input), 21);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day08.parse_file("test_input.txt");
+        assert_eq!(Day08.part2(&input), 8);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day08);
+    }
+
+    #[test]
+    fn alternatives() {
+        common::differential::check(|| Day08, &["test_input.txt", "input.txt"], 0..50, 10);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day08, 0..20, &[0, 1, 10, 50]);
+    }
+}
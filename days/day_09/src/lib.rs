@@ -0,0 +1,241 @@
+use common::generate::{Generated, Rng};
+use common::simulation::{Driver, Simulation};
+use common::visualise::{Canvas, Frames, Pixel};
+use common::{config, parse, ParseError, Solution};
+use std::collections::HashSet;
+
+pub type Point = (i32, i32);
+pub type Motion = (u8, usize);
+
+// Parse the puzzle input into motions of the head of the rope
+pub fn parse_motions(s: &str) -> Result<Vec<Motion>, ParseError> {
+    parse::lines(s)
+        .map(|line| {
+            let (a, b) = line.split_once(line.text, " ", "Cannot split line")?;
+            let direction = match a.as_bytes() {
+                [direction @ (b'R' | b'L' | b'U' | b'D')] => *direction,
+                _ => return Err(line.error(a, "Expected a direction of R, L, U or D")),
+            };
+            Ok((
+                direction,
+                line.parse::<usize>(b, "Cannot parse steps as usize")?,
+            ))
+        })
+        .collect()
+}
+
+fn drag(x: i32, y: i32) -> i32 {
+    y + x.cmp(&y) as i32
+}
+
+pub fn drag_rope(direction: u8, knots: &mut [Point]) -> Point {
+    match direction {
+        b'R' => knots[0].0 += 1,
+        b'L' => knots[0].0 -= 1,
+        b'U' => knots[0].1 += 1,
+        b'D' => knots[0].1 -= 1,
+        _ => {}
+    }
+    for i in 0..knots.len() - 1 {
+        let ((hx, hy), (tx, ty)) = (knots[i], knots[i + 1]);
+        if (hx - tx).abs() > 1 || (hy - ty).abs() > 1 {
+            knots[i + 1] = (drag(hx, tx), drag(hy, ty));
+        }
+    }
+    knots[knots.len() - 1]
+}
+
+// The rope being dragged about by its head, one step of the head at a time
+struct Rope {
+    knots: Vec<Point>,
+    steps: Vec<u8>, // The direction of each step of the head
+    done: usize,    // The steps taken so far
+    visited: HashSet<Point>,
+}
+
+impl Rope {
+    fn new(motions: &[Motion], knots: usize) -> Self {
+        Rope {
+            knots: vec![(0, 0); knots],
+            steps: motions
+                .iter()
+                .flat_map(|&(direction, steps)| vec![direction; steps])
+                .collect(),
+            done: 0,
+            visited: HashSet::new(),
+        }
+    }
+}
+
+impl Simulation for Rope {
+    type Snapshot = Vec<Point>;
+
+    fn step(&mut self) {
+        let tail = drag_rope(self.steps[self.done], &mut self.knots);
+        self.visited.insert(tail);
+        self.done += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.done == self.steps.len()
+    }
+
+    fn snapshot(&self) -> Vec<Point> {
+        self.knots.clone()
+    }
+}
+
+pub fn count_tail_positions(motions: &[Motion], knots: usize) -> usize {
+    let mut rope = Rope::new(motions, knots);
+    Driver::new().run(&mut rope);
+    rope.visited.len()
+}
+
+const EMPTY: Pixel = Pixel::new('.', [20, 20, 20]);
+const VISITED: Pixel = Pixel::new('#', [90, 90, 200]);
+const KNOT: Pixel = Pixel::new('o', [255, 160, 60]);
+const HEAD: Pixel = Pixel::new('H', [255, 60, 60]);
+
+// Draw the rope after every step of its head, along with the positions its tail
+// has visited
+fn draw_rope(motions: &[Motion], knots: usize, frames: &mut Frames) {
+    // The head goes at least as far as any other knot, so the canvas only has
+    // to cover where the head goes
+    // Note: y is negated, so that up is drawn at the top
+    let mut head = Rope::new(motions, 1);
+    let (mut min, mut max) = ((0, 0), (0, 0));
+    Driver::new()
+        .on_step(|_, head: &Rope| {
+            let (x, y) = head.knots[0];
+            min = (min.0.min(x as i64), min.1.min(-y as i64));
+            max = (max.0.max(x as i64), max.1.max(-y as i64));
+        })
+        .run(&mut head);
+    let mut visited = Canvas::new(min, max, 200, EMPTY);
+    visited.draw((0, 0), VISITED);
+
+    let draw = |visited: &Canvas, rope: &Rope| {
+        let mut canvas = visited.clone();
+        for (index, &(x, y)) in rope.knots.iter().enumerate().rev() {
+            let pixel = if index == 0 { HEAD } else { KNOT };
+            canvas.draw((x as i64, -y as i64), pixel);
+        }
+        canvas.frame
+    };
+    Driver::new()
+        .on_step(|_, rope: &Rope| {
+            let (x, y) = rope.knots[rope.knots.len() - 1];
+            visited.draw((x as i64, -y as i64), VISITED);
+            frames.step(|| draw(&visited, rope));
+        })
+        .run(&mut Rope::new(motions, knots));
+    frames.finish(|| visited.frame);
+}
+
+pub struct Day09 {
+    pub knots: (usize, usize), // Number of knots in the rope for part 1 and part 2
+}
+
+impl Default for Day09 {
+    fn default() -> Self {
+        Day09 { knots: (2, 10) }
+    }
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<Motion>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_motions(input)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "knots" => self.knots = config::pair(name, value)?,
+            _ => return Err(format!("Day 9 has no parameter {name:?}")),
+        }
+        if self.knots.0 == 0 || self.knots.1 == 0 {
+            return Err("Expected a rope of at least 1 knot".to_string());
+        }
+        Ok(())
+    }
+
+    fn part1(&self, motions: &Self::Input) -> usize {
+        count_tail_positions(motions, self.knots.0)
+    }
+
+    fn part2(&self, motions: &Self::Input) -> usize {
+        count_tail_positions(motions, self.knots.1)
+    }
+
+    fn visualise(&self, motions: &Self::Input, part: u8, frames: &mut Frames) -> bool {
+        let knots = if part == 1 {
+            self.knots.0
+        } else {
+            self.knots.1
+        };
+        draw_rope(motions, knots, frames);
+        true
+    }
+
+    // `size` motions of the head of the rope
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let input = (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['R', 'L', 'U', 'D']),
+                    rng.between(1, 20)
+                )
+            })
+            .collect::<String>();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day09::default().parse_file("test_input_0.txt");
+        assert_eq!(Day09::default().part1(&input), 13);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day09::default().parse_file("test_input_0.txt");
+        assert_eq!(Day09::default().part2(&input), 1);
+        let input = Day09::default().parse_file("test_input_1.txt");
+        assert_eq!(Day09::default().part2(&input), 36);
+    }
+
+    #[test]
+    fn visualise() {
+        let input = Day09::default().parse_file("test_input_0.txt");
+        let frames = common::visualise::frames(&Day09::default(), &input, 1).unwrap();
+        assert_eq!(frames.len(), 25);
+        let last = common::visualise::ascii(frames.last().unwrap());
+        assert_eq!(last, "..##..\n...##.\n.####.\n....#.\n####..\n");
+        assert_eq!(
+            common::visualise::ascii(&frames[23]),
+            "..##..\n...##.\n.oH##.\n....#.\n####..\n"
+        );
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(Day09::default);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(Day09::default, 0..20, &[0, 1, 10, 100]);
+    }
+}
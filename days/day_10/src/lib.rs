@@ -0,0 +1,224 @@
+use common::generate::{Generated, Rng};
+use common::simulation::{Driver, Simulation};
+use common::visualise::{Frames, Pixel};
+use common::{parse, Coord, Grid, ParseError, Solution};
+
+// Note: we can transform: `"noop"` and `"addx X"` into `["noop"]` and `["addx", "X"]`;
+// this maps each item to as many cycles it takes to do the initial item. We can then
+// run the items a cycle at a time (see `Cpu`), discarding them (except for "X" which
+// we parse into an integer).
+
+// Parse the puzzle input into the items of the program
+pub fn parse_program(s: &str) -> Result<Vec<String>, ParseError> {
+    parse::lines(s)
+        .map(|line| match line.text.split(' ').collect::<Vec<_>>()[..] {
+            ["noop"] => Ok(vec!["noop".to_string()]),
+            ["addx", x] => {
+                line.parse::<i32>(x, "Cannot parse addx value as i32")?;
+                Ok(vec!["addx".to_string(), x.to_string()])
+            }
+            _ => Err(line.error(line.text, "Expected an instruction of noop or addx X")),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|items| items.into_iter().flatten().collect())
+}
+
+// The CPU running the program, one cycle per step
+struct Cpu<'a> {
+    items: &'a [String],
+    cycle_no: i32, // The cycles run so far
+    x: i32,
+    during: i32, // The value of X *during* the last cycle, rather than after it
+}
+
+impl Simulation for Cpu<'_> {
+    type Snapshot = (i32, i32);
+
+    fn step(&mut self) {
+        self.during = self.x;
+        self.x += self.items[self.cycle_no as usize]
+            .parse::<i32>()
+            .unwrap_or(0);
+        self.cycle_no += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.cycle_no as usize == self.items.len()
+    }
+
+    // The cycle, and the value of X during it
+    fn snapshot(&self) -> (i32, i32) {
+        (self.cycle_no, self.during)
+    }
+}
+
+pub fn cycles(items: &[String]) -> Vec<(i32, i32)> {
+    let mut cpu = Cpu {
+        items,
+        cycle_no: 0,
+        x: 1,
+        during: 1,
+    };
+    Driver::new()
+        .checkpoints(1)
+        .run(&mut cpu)
+        .checkpoints
+        .into_iter()
+        .map(|(_, snapshot)| snapshot)
+        .collect::<Vec<_>>()
+}
+
+pub fn sum_of_signal_strengths(items: &[String]) -> i32 {
+    cycles(items)
+        .into_iter()
+        .filter(|(cycle_no, _)| (cycle_no + 20) % 40 == 0)
+        .map(|(cycle_no, x)| cycle_no * x)
+        .sum()
+}
+
+pub fn render_image(items: &[String]) -> String {
+    cycles(items)
+        .into_iter()
+        .map(|(cycle_no, x)| {
+            (
+                cycle_no,
+                if (x - 1..=x + 1).contains(&((cycle_no - 1) % 40)) {
+                    "#"
+                } else {
+                    "."
+                },
+            )
+        })
+        .map(|(cycle_no, c)| {
+            if cycle_no % 40 == 0 {
+                format!("{c}\r\n")
+            } else {
+                c.to_string()
+            }
+        })
+        .collect::<String>()
+}
+
+const DARK: Pixel = Pixel::new('.', [20, 20, 20]);
+const LIT: Pixel = Pixel::new('#', [80, 255, 80]);
+const SPRITE: Pixel = Pixel::new('~', [60, 60, 160]);
+
+// Draw the image a cycle at a time, along with where the sprite is on the row
+// still being drawn
+fn draw_image(items: &[String], frames: &mut Frames) {
+    let cycles = cycles(items);
+    let mut crt = Grid::new(40, cycles.len().div_ceil(40).max(1), DARK);
+    for (cycle_no, x) in cycles {
+        let (column, row) = ((cycle_no - 1) % 40, (cycle_no - 1) / 40);
+        if (x - 1..=x + 1).contains(&column) {
+            crt[Coord::new(column as usize, row as usize)] = LIT;
+        }
+        frames.step(|| {
+            let mut frame = crt.clone();
+            for sprite in (x - 1..=x + 1).filter(|sprite| (column + 1..40).contains(sprite)) {
+                frame[Coord::new(sprite as usize, row as usize)] = SPRITE;
+            }
+            frame
+        });
+    }
+    frames.finish(|| crt);
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<String>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_program(input)
+    }
+
+    fn part1(&self, items: &Self::Input) -> i32 {
+        sum_of_signal_strengths(items)
+    }
+
+    fn part2(&self, items: &Self::Input) -> String {
+        render_image(items)
+    }
+
+    // Only part 2 draws anything
+    fn visualise(&self, items: &Self::Input, part: u8, frames: &mut Frames) -> bool {
+        if part == 2 {
+            draw_image(items, frames);
+        }
+        part == 2
+    }
+
+    // A program of `size` instructions, which keeps the sprite on the screen
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut x = 1;
+        let input = (0..size.max(1))
+            .map(|_| {
+                if rng.chance(0.3) {
+                    return "noop\n".to_string();
+                }
+                let value = rng.between(-(x.min(10)), (39 - x).min(10));
+                x += value;
+                format!("addx {value}\n")
+            })
+            .collect::<String>();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day10.parse_file("test_input.txt");
+        assert_eq!(Day10.part1(&input), 13140);
+    }
+
+    #[test]
+    fn part2() {
+        // The image itself is checked against its snapshot (see `snapshot`)
+        let input = Day10.parse_file("test_input.txt");
+        let image = Day10.part2(&input);
+        assert_eq!(image.lines().map(str::len).collect::<Vec<_>>(), [40; 6]);
+        assert_eq!(image.matches("\r\n").count(), 6);
+    }
+
+    #[test]
+    fn visualise() {
+        let input = Day10.parse_file("test_input.txt");
+        let frames = common::visualise::frames(&Day10, &input, 2).unwrap();
+        assert_eq!(frames.len(), 241);
+        assert_eq!(
+            common::visualise::ascii(&frames[1]).lines().next(),
+            Some("##~.....................................")
+        );
+        assert_eq!(
+            common::visualise::ascii(&frames[240]),
+            Day10.part2(&input).replace("\r\n", "\n")
+        );
+        assert!(common::visualise::frames(&Day10, &input, 1).is_none());
+    }
+
+    #[test]
+    fn snapshot() {
+        let input = Day10.parse_file("test_input.txt");
+        common::snapshot::check(Day10::RES_DIR, "test_input_image", &render_image(&input));
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day10);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day10, 0..20, &[0, 1, 10, 100, 240]);
+    }
+}
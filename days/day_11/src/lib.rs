@@ -0,0 +1,397 @@
+use common::generate::{Generated, Rng};
+use common::parse::{self, Record};
+use common::simulation::{Driver, Simulation};
+use common::{config, Line, ParseError, Solution};
+use std::ops::Add; // for `usize::add`
+use std::ops::Mul; // for `usize::mul`
+use std::rc::Rc;
+
+// Note: `Rc` rather than `Box`, so that each part can clone the monkeys it is given
+pub type Operation = Rc<dyn Fn(usize) -> usize>;
+
+#[derive(Clone)]
+pub struct Monkey {
+    pub items: Vec<usize>,
+    pub op: Operation,
+    pub divisor: usize,
+    pub if_true: usize,
+    pub if_false: usize,
+    pub inspection_count: usize,
+}
+
+impl Monkey {
+    fn inspect(&self, worry_level: usize) -> usize {
+        (self.op)(worry_level)
+    }
+
+    fn test(&self, worry_level: usize) -> usize {
+        if worry_level.is_multiple_of(self.divisor) {
+            self.if_true
+        } else {
+            self.if_false
+        }
+    }
+}
+
+// Parse the single integer within a value e.g. "divisible by 23", keeping
+// its text so that errors can point at it
+fn single_value<'a>(
+    line: &Line<'a>,
+    value: &'a str,
+    message: &str,
+) -> Result<(&'a str, usize), ParseError> {
+    match parse::integers(value).collect::<Vec<_>>()[..] {
+        [text] => Ok((text, line.parse::<usize>(text, message)?)),
+        _ => Err(line.error(value, message)),
+    }
+}
+
+// Parse a monkey, given the number of monkeys (so that we can check who it throws to)
+//
+// e.g.
+//
+// Monkey 0:
+//   Starting items: 79, 98
+//   Operation: new = old * 19
+//   Test: divisible by 23
+//     If true: throw to monkey 2
+//     If false: throw to monkey 3
+//
+fn parse_monkey(lines: &[Line], monkeys: usize) -> Result<Monkey, ParseError> {
+    let record = Record::new(lines, ":")?;
+
+    let (line, items) = record.field("Starting items")?;
+    let items = line.integers::<usize>(items, "Cannot parse item as usize")?;
+
+    let (line, op_info) = record.field("Operation")?;
+    let (_, op_info) = line.split_once(op_info, "new = old ", "Cannot find operation")?;
+    let (operator, value) = line.split_once(op_info, " ", "Cannot split operation")?;
+    let operator = match operator {
+        "+" => usize::add,
+        "*" => usize::mul,
+        _ => return Err(line.error(operator, "Expected an operator of + or *")),
+    };
+    let op: Operation = if value == "old" {
+        Rc::new(move |old| operator(old, old))
+    } else {
+        let val = line.parse::<usize>(value, "Cannot parse operation value as usize")?;
+        Rc::new(move |old| operator(old, val))
+    };
+
+    let (line, test) = record.field("Test")?;
+    let (divisor_text, divisor) = single_value(&line, test, "Cannot parse divisor as usize")?;
+    if divisor == 0 {
+        return Err(line.error(divisor_text, "Cannot test whether divisible by 0"));
+    }
+    let throw_to = |key: &str| {
+        let (line, value) = record.field(key)?;
+        let (text, id) = single_value(&line, value, "Cannot parse monkey as usize")?;
+        if id < monkeys {
+            Ok(id)
+        } else {
+            Err(line.error(text, &format!("There is no monkey {id}")))
+        }
+    };
+    let if_true = throw_to("If true")?;
+    let if_false = throw_to("If false")?;
+
+    Ok(Monkey {
+        items,
+        op,
+        divisor,
+        if_true,
+        if_false,
+        inspection_count: 0,
+    })
+}
+
+// Parse the puzzle input into monkeys
+pub fn parse_monkeys(s: &str) -> Result<Vec<Monkey>, ParseError> {
+    let groups = parse::groups(s);
+    groups
+        .iter()
+        .map(|lines| parse_monkey(lines, groups.len()))
+        .collect()
+}
+
+pub fn round(monkeys: &mut [Monkey], lcm: usize, f: fn(usize, usize) -> usize) {
+    for i in 0..monkeys.len() {
+        let items = monkeys[i].items.clone();
+        for item in items {
+            let worry_level = f(monkeys[i].inspect(item), lcm);
+            monkeys[i].inspection_count += 1;
+            let id = monkeys[i].test(worry_level);
+            monkeys[id].items.push(worry_level);
+        }
+        monkeys[i].items.clear();
+    }
+}
+
+// The monkeys throwing items to each other, one round per step
+struct KeepAway {
+    monkeys: Vec<Monkey>,
+    lcm: usize,
+    f: fn(usize, usize) -> usize, // How worry levels are kept down after each inspection
+    round: usize,                 // The rounds played so far
+    rounds: usize,
+}
+
+impl Simulation for KeepAway {
+    type Snapshot = Vec<usize>;
+
+    fn step(&mut self) {
+        round(&mut self.monkeys, self.lcm, self.f);
+        self.round += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.round == self.rounds
+    }
+
+    // The number of items each monkey has inspected so far
+    fn snapshot(&self) -> Vec<usize> {
+        self.monkeys
+            .iter()
+            .map(|monkey| monkey.inspection_count)
+            .collect::<Vec<_>>()
+    }
+}
+
+pub fn monkey_business(monkeys: &[Monkey], rounds: usize, f: fn(usize, usize) -> usize) -> usize {
+    let lcm = monkeys
+        .iter()
+        .map(|monkey| monkey.divisor)
+        .product::<usize>();
+    let mut keep_away = KeepAway {
+        monkeys: monkeys.to_vec(),
+        lcm,
+        f,
+        round: 0,
+        rounds,
+    };
+    Driver::new().run(&mut keep_away);
+    let mut inspection_counts = keep_away.snapshot();
+    inspection_counts.sort_by(|a, b| b.cmp(a));
+    inspection_counts[0] * inspection_counts[1]
+}
+
+// Note: We can use the Lowest Common Multiplier (LCM) of the monkeys' divisors
+// in order to make the worry level smaller and therefore prevent overflowing.
+// We don't care about the actual worry level; we only care that we can still
+// test the item.
+//
+// Initially for Part 2 a `Vec<Vec<usize>>` was used. For each monkey and for each
+// item, the remainder of the divisor was stored in order to keep prevent overflowing.
+// That method was objectively slower than using the LCM. However, note that if the
+// LCM is quite a large number, then we might overflow before doing `% lcm` i.e. our
+// method for making the number smaller. Meanwhile, the `Vec<Vec<usize>>` approach only
+// really has to make sure that the maximum divisor of all the monkeys' divisors is not
+// a very large number, which is more reasonable.
+//
+// We will keep the LCM method here as it is better in terms of time and space.
+pub fn part_01(worry_level: usize, _: usize) -> usize {
+    worry_level / 3
+}
+
+pub fn part_02(worry_level: usize, lcm: usize) -> usize {
+    worry_level % lcm
+}
+
+// A monkey to generate a puzzle input from
+struct Blueprint {
+    items: Vec<usize>,
+    operator: char,
+    value: Option<usize>, // `None` for "old"
+    divisor: usize,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Blueprint {
+    // Inspect an item as in part 1, unless that would overflow
+    fn inspect(&self, worry_level: usize) -> Option<usize> {
+        let value = self.value.unwrap_or(worry_level);
+        match self.operator {
+            '+' => worry_level.checked_add(value),
+            _ => worry_level.checked_mul(value),
+        }
+        .map(|worry_level| worry_level / 3)
+    }
+
+    fn describe(&self, index: usize) -> String {
+        let items = self
+            .items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let value = self
+            .value
+            .map_or("old".to_string(), |value| value.to_string());
+        [
+            format!("Monkey {index}:"),
+            format!("  Starting items: {items}"),
+            format!("  Operation: new = old {} {value}", self.operator),
+            format!("  Test: divisible by {}", self.divisor),
+            format!("    If true: throw to monkey {}", self.if_true),
+            format!("    If false: throw to monkey {}", self.if_false),
+        ]
+        .map(|line| line + "\n")
+        .concat()
+    }
+}
+
+// Whether any worry level would overflow during the rounds of part 1
+fn overflows(blueprints: &[Blueprint], rounds: usize) -> bool {
+    let mut items = blueprints
+        .iter()
+        .map(|blueprint| blueprint.items.clone())
+        .collect::<Vec<_>>();
+    for _ in 0..rounds {
+        for (index, blueprint) in blueprints.iter().enumerate() {
+            for item in std::mem::take(&mut items[index]) {
+                let Some(worry_level) = blueprint.inspect(item) else {
+                    return true;
+                };
+                let id = if worry_level.is_multiple_of(blueprint.divisor) {
+                    blueprint.if_true
+                } else {
+                    blueprint.if_false
+                };
+                items[id].push(worry_level);
+            }
+        }
+    }
+    false
+}
+
+pub struct Day11 {
+    pub rounds: (usize, usize), // Number of rounds for part 1 and part 2
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 {
+            rounds: (20, 10000),
+        }
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "rounds" => self.rounds = config::pair(name, value)?,
+            _ => return Err(format!("Day 11 has no parameter {name:?}")),
+        }
+        Ok(())
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> usize {
+        monkey_business(monkeys, self.rounds.0, part_01)
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> usize {
+        monkey_business(monkeys, self.rounds.1, part_02)
+    }
+
+    // `size` items, spread between 3 to 8 monkeys
+    //
+    // Note: the divisors are distinct primes, whose product is small enough
+    // that part 2 never overflows; monkeys which would overflow in part 1
+    // (i.e. when their worry levels grow too quickly) are thrown away
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        loop {
+            let count = rng.between(3, 8) as usize;
+            let mut divisors = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+            rng.shuffle(&mut divisors);
+            let mut blueprints = (0..count)
+                .map(|index| {
+                    let (operator, value) = match rng.below(20) {
+                        0..=9 => ('+', Some(rng.between(1, 9) as usize)),
+                        10..=17 => ('*', Some(rng.between(2, 19) as usize)),
+                        _ => ('*', None),
+                    };
+                    let mut others = (0..count)
+                        .filter(|&other| other != index)
+                        .collect::<Vec<_>>();
+                    rng.shuffle(&mut others);
+                    Blueprint {
+                        items: Vec::new(),
+                        operator,
+                        value,
+                        divisor: divisors[index],
+                        if_true: others[0],
+                        if_false: others[1],
+                    }
+                })
+                .collect::<Vec<_>>();
+            for _ in 0..size {
+                let monkey = rng.below(count);
+                blueprints[monkey].items.push(rng.between(50, 99) as usize);
+            }
+            if !overflows(&blueprints, self.rounds.0) {
+                let input = blueprints
+                    .iter()
+                    .enumerate()
+                    .map(|(index, blueprint)| blueprint.describe(index))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                return Some(Generated::new(input));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day11::default().parse_file("test_input.txt");
+        assert_eq!(Day11::default().part1(&input), 10605);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day11::default().parse_file("test_input.txt");
+        assert_eq!(Day11::default().part2(&input), 2713310158);
+    }
+
+    #[test]
+    fn rounds() {
+        let input = Day11::default().parse_file("test_input.txt");
+        let lcm = input.iter().map(|monkey| monkey.divisor).product::<usize>();
+        let mut keep_away = KeepAway {
+            monkeys: input,
+            lcm,
+            f: part_02,
+            round: 0,
+            rounds: 10000,
+        };
+        let run = Driver::new().checkpoints(1000).run(&mut keep_away);
+        assert_eq!(run.checkpoints[0], (1000, vec![5204, 4792, 199, 5192]));
+        assert_eq!(run.checkpoints[9], (10000, vec![52166, 47830, 1938, 52013]));
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(Day11::default);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(Day11::default, 0..20, &[0, 1, 10, 100]);
+    }
+}
@@ -0,0 +1,250 @@
+use common::generate::{Generated, Rng};
+use common::visualise::{Frames, Pixel};
+use common::{Coord, Grid, ParseError, Solution};
+use pathfinding::prelude::dijkstra;
+use std::collections::VecDeque;
+
+pub type CostFunction = fn(i32, i32) -> bool;
+pub type GoalFunction = fn(&Coord, &Grid<u8>) -> bool;
+
+// Parse the puzzle input into a heightmap
+pub fn parse_grid(s: &str) -> Result<Grid<u8>, ParseError> {
+    let grid = Grid::parse(
+        s,
+        |c| (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c as u8),
+        "Expected an elevation from a-z, or S or E",
+    )?;
+    for (search, message) in [
+        (b'S', "Cannot find start position"),
+        (b'E', "Cannot find best signal"),
+    ] {
+        if grid.position(|&c| c == search).is_none() {
+            return Err(ParseError::end_of_input(s, message));
+        }
+    }
+    Ok(grid)
+}
+
+pub fn cost(value: u8) -> i32 {
+    (match value {
+        b'S' => b'a',
+        b'E' => b'z',
+        _ => value,
+    } - b'a') as i32
+}
+
+pub fn neighbours(pos: &Coord, grid: &Grid<u8>, f: CostFunction) -> Vec<(Coord, usize)> {
+    let c = cost(grid[*pos]);
+    grid.neighbours4(*pos)
+        .filter(|&next| f(cost(grid[next]), c))
+        .map(|next| (next, 1))
+        .collect::<Vec<_>>()
+}
+
+// The search for a part: where it starts, which steps can be taken, and where it ends
+pub fn search(part: u8) -> (u8, CostFunction, GoalFunction) {
+    match part {
+        1 => (b'S', |n, m| n - m <= 1, |&pos, grid| grid[pos] == b'E'),
+        _ => (
+            b'E',
+            |m, n| n - m <= 1,
+            |&pos, grid| [b'a', b'S'].contains(&grid[pos]),
+        ),
+    }
+}
+
+pub fn route(grid: &Grid<u8>, start: u8, f: CostFunction, g: GoalFunction) -> (Vec<Coord>, usize) {
+    let start = grid
+        .position(|&c| c == start)
+        .expect("Cannot find start position");
+    dijkstra(&start, |pos| neighbours(pos, grid, f), |pos| g(pos, grid))
+        .expect("Cannot find path in grid")
+}
+
+pub fn solve(grid: &Grid<u8>, start: u8, f: CostFunction, g: GoalFunction) -> usize {
+    route(grid, start, f, g).1
+}
+
+const ROUTE: [u8; 3] = [255, 60, 60];
+
+// Draw the heightmap (higher is lighter), and then the route taken across it a
+// step at a time, marked as in the puzzle with `>`, `<`, `^` and `v`
+fn draw_route(grid: &Grid<u8>, part: u8, frames: &mut Frames) {
+    let mut frame = Grid::new(grid.width(), grid.height(), Pixel::new('.', [0; 3]));
+    for (pos, &c) in grid.iter() {
+        let grey = 40 + 8 * cost(c) as u8;
+        frame[pos] = Pixel::new(c as char, [grey; 3]);
+    }
+    frames.step(|| frame.clone());
+    let (start, f, g) = search(part);
+    let (path, _) = route(grid, start, f, g);
+    for window in path.windows(2) {
+        let glyph = match (window[0], window[1]) {
+            (from, to) if to.x > from.x => '>',
+            (from, to) if to.x < from.x => '<',
+            (from, to) if to.y < from.y => '^',
+            _ => 'v',
+        };
+        frame[window[0]] = Pixel::new(glyph, ROUTE);
+        frames.step(|| frame.clone());
+    }
+    frames.finish(|| frame);
+}
+
+// Whether the best signal can be reached from the start, climbing at most one
+// higher with each step
+fn can_reach(grid: &Grid<u8>, start: Coord, end: Coord) -> bool {
+    let mut seen = Grid::new(grid.width(), grid.height(), false);
+    let mut queue = VecDeque::from([start]);
+    seen[start] = true;
+    while let Some(pos) = queue.pop_front() {
+        if pos == end {
+            return true;
+        }
+        for (next, _) in neighbours(&pos, grid, |n, m| n - m <= 1) {
+            if !seen[next] {
+                seen[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    false
+}
+
+// Generate a heightmap where the best signal can be reached from the start
+//
+// The heights are the highest of a few cones, each one lower with every step
+// away from its peak, so that every step can be climbed; the best signal is
+// the peak of the tallest cone. Then some squares are changed to any height
+// as obstacles, though fewer of them each time that leaves no way up.
+fn generate_heightmap(rng: &mut Rng, side: usize) -> Grid<u8> {
+    let random_coord = |rng: &mut Rng| Coord::new(rng.below(side), rng.below(side));
+    loop {
+        let end = random_coord(rng);
+        let mut peaks = vec![(end, 25)];
+        for _ in 0..side / 4 {
+            peaks.push((random_coord(rng), 1 + rng.below(24)));
+        }
+        let mut grid = Grid::new(side, side, b'a');
+        for pos in grid.coords().collect::<Vec<_>>() {
+            let height = peaks
+                .iter()
+                .map(|&(peak, height)| height.saturating_sub(peak.manhattan(pos)))
+                .max()
+                .unwrap_or(0);
+            grid[pos] = b'a' + height as u8;
+        }
+        let lowest = grid
+            .coords()
+            .filter(|&pos| grid[pos] == b'a')
+            .collect::<Vec<_>>();
+        if lowest.is_empty() {
+            continue;
+        }
+        let start = *rng.choose(&lowest);
+        grid[start] = b'S';
+        grid[end] = b'E';
+
+        let mut obstacles = side * side / 4;
+        loop {
+            let mut heightmap = grid.clone();
+            for _ in 0..obstacles {
+                let pos = random_coord(rng);
+                if pos != start && pos != end {
+                    heightmap[pos] = b'a' + rng.below(26) as u8;
+                }
+            }
+            if can_reach(&heightmap, start, end) {
+                return heightmap;
+            }
+            obstacles /= 2;
+        }
+    }
+}
+
+// Dijkstra's algorithm is an algorithm to find the best route from pos A to pos B.
+// Rather than implement it, we can simply use an implementation from a Rust crate
+// to solve part 1.
+//
+// For part 2, finding the best route from any 'a' to 'E' is the same as finding
+// the best route from 'E to any 'a' - which is what Dijkstra's algorithm does.
+// So to solve it, simply run dijkstra with flipped arguments:
+// - start is 'E', rather than 'S'
+// - `|m, n| n - m <= 1` rather than `|n, m| n - m <= 1` as we are descending rather than ascending
+// - goal is to find 'a' or 'S', rather than 'E'
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> usize {
+        let (start, f, g) = search(1);
+        solve(grid, start, f, g)
+    }
+
+    fn part2(&self, grid: &Self::Input) -> usize {
+        let (start, f, g) = search(2);
+        solve(grid, start, f, g)
+    }
+
+    fn visualise(&self, grid: &Self::Input, part: u8, frames: &mut Frames) -> bool {
+        draw_route(grid, part, frames);
+        true
+    }
+
+    // A heightmap `size` squares wide and tall (but at least 14, so that there
+    // is room to climb from a to z)
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let grid = generate_heightmap(rng, size.max(14));
+        Some(Generated::new(grid.render(|&c| c as char)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day12.parse_file("test_input.txt");
+        assert_eq!(Day12.part1(&input), 31);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day12.parse_file("test_input.txt");
+        assert_eq!(Day12.part2(&input), 29);
+    }
+
+    #[test]
+    fn visualise() {
+        let input = Day12.parse_file("test_input.txt");
+        let frames = common::visualise::frames(&Day12, &input, 1).unwrap();
+        assert_eq!(frames.len(), 31 + 2);
+        assert_eq!(
+            common::visualise::ascii(&frames[0]),
+            input.render(|&c| c as char)
+        );
+        let last = frames.last().unwrap();
+        assert_eq!(last.iter().filter(|(_, p)| p.colour == ROUTE).count(), 31);
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day12);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day12, 0..20, &[0, 1, 20, 50]);
+    }
+}
@@ -0,0 +1,29 @@
+use common::Coord;
+use day_12::{cost, neighbours, parse_grid, route, search, solve};
+
+// A ramp from `a` up to `E`, above a row of `a`s to start from
+const RAMP: &str = "abcdefghijklmnopqrstuvwxyE\nSaaaaaaaaaaaaaaaaaaaaaaaaa\n";
+
+#[test]
+fn elevations() {
+    assert_eq!([b'S', b'a', b'q', b'z', b'E'].map(cost), [0, 0, 16, 25, 25]);
+    assert!(parse_grid("abc\nSbc\n").is_err());
+}
+
+#[test]
+fn climb() {
+    let grid = parse_grid(RAMP).unwrap();
+    let (start, f, g) = search(1);
+    let (path, cost) = route(&grid, start, f, g);
+    assert_eq!(cost, 26);
+    assert_eq!(path.first(), Some(&Coord::new(0, 1)));
+    assert_eq!(path.last(), Some(&Coord::new(25, 0)));
+
+    // Coming back down, each step can be at most one lower
+    let (start, f, g) = search(2);
+    assert_eq!(
+        neighbours(&Coord::new(25, 0), &grid, f),
+        [(Coord::new(24, 0), 1)]
+    );
+    assert_eq!(solve(&grid, start, f, g), 25);
+}
@@ -0,0 +1,343 @@
+use common::generate::{Generated, Rng};
+use common::repl::{self, Repl};
+use common::{parse, Line, ParseError, Solution};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::zip;
+
+// Note: derive PartialEq for us, as `divider_packets.contains` requires `==`
+#[derive(Clone, PartialEq)]
+pub enum Packet {
+    Integer(usize),
+    List(Vec<Packet>),
+}
+
+// Point at the byte at `index` of the line, or at the end of the line if there is none
+fn error_at(line: &Line, index: usize, message: &str) -> ParseError {
+    let end = (index + 1).min(line.text.len());
+    line.error(&line.text[index.min(end)..end], message)
+}
+
+fn parse_integer(line: &Line, index: usize) -> Result<(Packet, usize), ParseError> {
+    let bytes = line.text.as_bytes();
+    let start = index;
+    let mut end = index;
+    while end < bytes.len() && bytes[end].is_ascii_digit() {
+        end += 1;
+    }
+    if start == end {
+        return Err(error_at(line, index, "Expected an integer or a list"));
+    }
+    let value = line.parse::<usize>(&line.text[start..end], "Cannot parse integer in packet")?;
+    Ok((Packet::Integer(value), end))
+}
+
+fn parse_list(line: &Line, mut index: usize) -> Result<(Packet, usize), ParseError> {
+    let bytes = line.text.as_bytes();
+    let mut packets = Vec::<Packet>::new();
+    index += 1; // Skip b'['
+    loop {
+        match bytes.get(index) {
+            Some(b']') => {
+                index += 1; // Skip b']'
+                break;
+            }
+            Some(b',') => {
+                index += 1; // Skip b','
+            }
+            Some(_) => {
+                let (packet, i) = parse_packet(line, index)?;
+                index = i;
+                packets.push(packet);
+            }
+            None => return Err(error_at(line, index, "Expected ] to close the list")),
+        }
+    }
+    Ok((Packet::List(packets), index))
+}
+
+fn parse_packet(line: &Line, index: usize) -> Result<(Packet, usize), ParseError> {
+    match line.text.as_bytes().get(index) {
+        Some(b'[') => parse_list(line, index),
+        _ => parse_integer(line, index),
+    }
+}
+
+// Parse a whole line as a single packet, with nothing left over
+fn parse_line(line: &Line) -> Result<Packet, ParseError> {
+    line.check_chars(|c| c.is_ascii(), "Expected only ASCII in packet")?;
+    let (packet, end) = parse_packet(line, 0)?;
+    if end < line.text.len() {
+        return Err(line.error(&line.text[end..], "Unexpected text after packet"));
+    }
+    Ok(packet)
+}
+
+// Parse the puzzle input into pairs of packets
+pub fn parse_pairs(s: &str) -> Result<Vec<[Packet; 2]>, ParseError> {
+    parse::groups(s)
+        .iter()
+        .map(|lines| match &lines[..] {
+            [a, b] => Ok([parse_line(a)?, parse_line(b)?]),
+            _ => {
+                let last = lines[lines.len() - 1];
+                Err(last.error(last.text, "Expected a pair of packets"))
+            }
+        })
+        .collect()
+}
+
+pub fn in_right_order(a: &Packet, b: &Packet) -> Ordering {
+    match (a, b) {
+        // (Integer, Integer)
+        (Packet::Integer(a), Packet::Integer(b)) => a.cmp(b),
+        // (List, List)
+        (Packet::List(a), Packet::List(b)) => zip(a.iter(), b.iter())
+            .map(|(x, y)| in_right_order(x, y))
+            .find(|&ordering| ordering != Ordering::Equal)
+            .unwrap_or(a.len().cmp(&b.len())),
+        // (Integer, List)
+        (Packet::Integer(a), b) => in_right_order(&Packet::List(vec![Packet::Integer(*a)]), b),
+        // (List, Integer)
+        (a, b) => in_right_order(b, a).reverse(),
+    }
+}
+
+pub fn sum_of_right_orders(pairs: &[[Packet; 2]]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, [a, b])| in_right_order(a, b) == Ordering::Less)
+        .map(|(index, _)| index + 1)
+        .sum()
+}
+
+pub fn locate_divider_packets(pairs: &[[Packet; 2]]) -> usize {
+    let divider_packets = [2, 6]
+        .into_iter()
+        .map(|val| Packet::List(vec![Packet::List(vec![Packet::Integer(val)])]))
+        .collect::<Vec<_>>();
+    let mut packets = pairs
+        .iter()
+        .flatten()
+        .chain(divider_packets.iter())
+        .collect::<Vec<_>>();
+    packets.sort_by(|a, b| in_right_order(a, b));
+    packets
+        .iter()
+        .enumerate()
+        .filter(|&(_, packet)| divider_packets.contains(packet))
+        .map(|(index, _)| index + 1)
+        .product()
+}
+
+// Which order a pair of packets is in, in words
+fn describe(a: &Packet, b: &Packet) -> String {
+    let order = match in_right_order(a, b) {
+        Ordering::Less => "in the right order",
+        Ordering::Equal => "the same",
+        Ordering::Greater => "not in the right order",
+    };
+    format!("{a}\n{b}\n{order}\n")
+}
+
+// Commands to compare packets in `aoc repl`
+struct Packets<'a> {
+    pairs: &'a [[Packet; 2]],
+}
+
+impl Repl for Packets<'_> {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            (
+                "compare <n>",
+                "Whether the nth pair (from 1) is in the right order",
+            ),
+            (
+                "order <left> <right>",
+                "Whether two packets of your own are in the right order",
+            ),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "compare" => {
+                let n = repl::arg::<usize>(args, 0, "n")?;
+                let [a, b] = n
+                    .checked_sub(1)
+                    .and_then(|index| self.pairs.get(index))
+                    .ok_or_else(|| format!("Expected a pair from 1 to {}", self.pairs.len()))?;
+                Ok(describe(a, b))
+            }
+            "order" => {
+                let packets = (0..2)
+                    .map(|index| {
+                        let packet = repl::arg::<String>(args, index, ["left", "right"][index])?;
+                        let line = parse::lines(&packet).next().ok_or("Expected a packet")?;
+                        parse_line(&line).map_err(|error| error.to_string())
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                Ok(describe(&packets[0], &packets[1]))
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Integer(value) => write!(f, "{value}"),
+            Packet::List(packets) => {
+                write!(f, "[")?;
+                for (index, packet) in packets.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{packet}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+// Generate a list of up to `length` packets, nested up to `depth` lists deep
+//
+// Note: on average, a list holds less than one other list, or else the
+// number of lists grows exponentially with depth
+fn generate_list(rng: &mut Rng, depth: usize, length: usize) -> Packet {
+    let packets = (0..rng.below(length + 1))
+        .map(|_| {
+            if depth > 0 && rng.chance(1.5 / length as f64) {
+                generate_list(rng, depth - 1, length)
+            } else {
+                Packet::Integer(rng.below(11))
+            }
+        })
+        .collect();
+    Packet::List(packets)
+}
+
+// Change a packet slightly, so that pairs of packets are often alike up until
+// somewhere deep inside them
+fn mutate(packet: &Packet, rng: &mut Rng) -> Packet {
+    match packet {
+        Packet::Integer(value) => match rng.below(3) {
+            0 => Packet::List(vec![Packet::Integer(*value)]),
+            _ => Packet::Integer(rng.below(11)),
+        },
+        Packet::List(packets) => {
+            let mut packets = packets.clone();
+            match (packets.len(), rng.below(4)) {
+                (0, _) | (_, 0) => packets.push(Packet::Integer(rng.below(11))),
+                (_, 1) => {
+                    packets.pop();
+                }
+                (length, _) => {
+                    let index = rng.below(length);
+                    packets[index] = mutate(&packets[index], rng);
+                }
+            }
+            Packet::List(packets)
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<[Packet; 2]>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_pairs(input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> usize {
+        sum_of_right_orders(pairs)
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> usize {
+        locate_divider_packets(pairs)
+    }
+
+    fn repl<'a>(&'a self, pairs: &'a Self::Input) -> Option<Box<dyn Repl + 'a>> {
+        Some(Box::new(Packets { pairs }))
+    }
+
+    // `size` pairs of packets, which grow longer and more deeply nested with size
+    //
+    // Note: packets are never the same as the divider packets, which must be unique
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let (depth, length) = (2 + size / 25, 3 + size / 10);
+        let dividers = ["[[2]]", "[[6]]"];
+        let mut pairs = Vec::new();
+        while pairs.len() < size.max(1) {
+            let a = generate_list(rng, depth, length);
+            let b = if rng.chance(0.5) {
+                mutate(&a, rng)
+            } else {
+                generate_list(rng, depth, length)
+            };
+            let pair = format!("{a}\n{b}\n");
+            if ![a, b]
+                .iter()
+                .any(|packet| dividers.contains(&packet.to_string().as_str()))
+            {
+                pairs.push(pair);
+            }
+        }
+        Some(Generated::new(pairs.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day13.parse_file("test_input.txt");
+        assert_eq!(Day13.part1(&input), 13);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day13.parse_file("test_input.txt");
+        assert_eq!(Day13.part2(&input), 140);
+    }
+
+    #[test]
+    fn repl() {
+        let input = Day13.parse_file("test_input.txt");
+        let mut repl = Day13.repl(&input).unwrap();
+        assert_eq!(
+            repl.run("compare", &["3"]).unwrap(),
+            "[9]\n[[8,7,6]]\nnot in the right order\n"
+        );
+        assert!(repl.run("compare", &["0"]).is_err());
+        assert!(repl.run("compare", &["9"]).is_err());
+        assert_eq!(
+            repl.run("order", &["[[1],[2,3,4]]", "[[1],4]"]).unwrap(),
+            "[[1],[2,3,4]]\n[[1],4]\nin the right order\n"
+        );
+        assert!(repl.run("order", &["[9", "[8]"]).is_err());
+        assert!(repl.run("order", &["[9]"]).is_err());
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(|| Day13);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(|| Day13, 0..20, &[0, 1, 10, 100]);
+    }
+}
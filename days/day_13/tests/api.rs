@@ -0,0 +1,27 @@
+use day_13::{in_right_order, locate_divider_packets, parse_pairs, sum_of_right_orders, Packet};
+use std::cmp::Ordering;
+
+#[test]
+fn packets() {
+    let three = Packet::List(vec![Packet::Integer(3)]);
+    assert_eq!(three.to_string(), "[3]");
+    assert_eq!(in_right_order(&Packet::Integer(3), &three), Ordering::Equal);
+    assert_eq!(
+        in_right_order(&Packet::List(vec![]), &three),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn pairs() {
+    let pairs = parse_pairs("[1,[2]]\n[[1],3]\n\n[5]\n[[4]]\n").unwrap();
+    assert_eq!(in_right_order(&pairs[0][0], &pairs[0][1]), Ordering::Less);
+    assert_eq!(
+        in_right_order(&pairs[1][0], &pairs[1][1]),
+        Ordering::Greater
+    );
+    assert_eq!(sum_of_right_orders(&pairs), 1);
+    // [1,[2]], [[1],3], [[2]], [[4]], [5], [[6]]
+    assert_eq!(locate_divider_packets(&pairs), 3 * 6);
+    assert!(parse_pairs("[1,[2]\n[3]\n").is_err());
+}
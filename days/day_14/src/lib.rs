@@ -0,0 +1,543 @@
+use common::differential::Alternative;
+use common::generate::{Generated, Rng};
+use common::repl::{self, Repl};
+use common::simulation::{Driver, Simulation};
+use common::visualise::{Frame, Frames, Pixel};
+use common::{config, parse, Coord, Grid, ParseError, Solution};
+use std::collections::HashSet;
+
+pub type Path = Vec<Coord>;
+pub type Scan = Grid<char>;
+
+// Parse the puzzle input into the paths of rock
+pub fn parse_paths(s: &str) -> Result<Vec<Path>, ParseError> {
+    parse::lines(s)
+        .map(|line| {
+            let mut path = Path::new();
+            for coords in line.text.split(" -> ") {
+                let (x, y) = line.split_once(coords, ",", "Cannot split coords pair")?;
+                let coord = Coord::new(
+                    line.parse::<usize>(x, "Cannot parse x coordinate")?,
+                    line.parse::<usize>(y, "Cannot parse y coordinate")?,
+                );
+                if let Some(last) = path.last() {
+                    if last.x != coord.x && last.y != coord.y {
+                        return Err(line.error(coords, "Expected a vertical or horizontal line"));
+                    }
+                }
+                path.push(coord);
+            }
+            Ok(path)
+        })
+        .collect()
+}
+
+// Draw a line of rock, where `origin` is the position of the top-left of the scan
+fn draw_line(scan: &mut Scan, origin: Coord, start: Coord, end: Coord) {
+    let [x0, x1] = [start.x.min(end.x), start.x.max(end.x)];
+    let [y0, y1] = [start.y.min(end.y), start.y.max(end.y)];
+    if x0 == x1 || y0 == y1 {
+        for (x, y) in (x0..=x1).flat_map(|x| (y0..=y1).map(move |y| (x, y))) {
+            scan[Coord::new(x - origin.x, y - origin.y)] = '#';
+        }
+    } else {
+        // It's good to know none of the lines were diagonals
+        panic!("Found line which is neither vertical nor horizontal, but some kind of diagonal");
+    }
+}
+
+fn fall_and_rest(scan: &mut Scan, source: Coord) -> bool {
+    let mut sand = source;
+    while sand.y < scan.height() - 1 {
+        if let Some(next) = [0, -1, 1]
+            .into_iter()
+            .filter_map(|dx| sand.offset(dx, 1))
+            .find(|&next| scan[next] == '.')
+        {
+            // Keep falling
+            sand = next;
+        } else {
+            // At rest
+            scan[sand] = 'o';
+            return true;
+        }
+    }
+    scan[sand] = 'o';
+    false
+}
+
+// Sand falling from `(source, 0)` a unit at a time, until a unit falls past the
+// lowest rock or the source is blocked
+struct Sand {
+    scan: Scan,
+    source: Coord, // Within the scan
+    units: usize,  // The units of sand at rest so far
+    fallen: bool,  // Whether a unit has fallen past the lowest rock
+}
+
+impl Sand {
+    // `f` adds anything else to the scan, e.g. the floor in part 2
+    fn new(paths: &[Path], source: usize, f: fn(&mut Scan)) -> Self {
+        // Give a bit of extra room in the grid, for e.g. sand falling
+        // For Part 2 we increase the extra room to ensure the pyramid
+        // of sand can fall and be at rest.
+        let max_y = paths
+            .iter()
+            .flatten()
+            .fold(usize::MIN, |acc, coord| acc.max(coord.y));
+
+        // Note: also make room for any rock outside of the pyramid
+        let rock_x = paths.iter().flatten().map(|coord| coord.x);
+        let min_x = rock_x
+            .clone()
+            .fold(source.saturating_sub(max_y + 2), usize::min);
+        let max_x = rock_x.fold(source + max_y + 2, usize::max);
+        let min_y = 0; // Defined by sand source
+        let origin = Coord::new(min_x, min_y);
+        let (width, height) = (max_x - min_x + 2, max_y - min_y + 3);
+        let source = Coord::new(source - min_x, 0 - min_y);
+
+        let mut scan = Grid::new(width, height, '.');
+        paths.iter().for_each(|path| {
+            path[..]
+                .windows(2)
+                .for_each(|window| draw_line(&mut scan, origin, window[0], window[1]))
+        });
+        f(&mut scan);
+        scan[source] = '+';
+        Sand {
+            scan,
+            source,
+            units: 0,
+            fallen: false,
+        }
+    }
+}
+
+impl Simulation for Sand {
+    type Snapshot = Scan;
+
+    fn step(&mut self) {
+        if fall_and_rest(&mut self.scan, self.source) {
+            self.units += 1;
+        } else {
+            self.fallen = true;
+        }
+    }
+
+    // Note: sand at rest on the source blocks it, which is the end of part 2.
+    // It would only happen in part 1 if the rock could hold all of the sand,
+    // but then there would be no more to simulate either.
+    fn is_done(&self) -> bool {
+        self.fallen || self.scan[self.source] != '+'
+    }
+
+    fn snapshot(&self) -> Scan {
+        self.scan.clone()
+    }
+}
+
+pub fn add_floor(scan: &mut Scan) {
+    let height = scan.height();
+    scan.row_mut(height - 1).fill('#')
+}
+
+fn settle(paths: &[Path], source: usize, f: fn(&mut Scan)) -> Sand {
+    let mut sand = Sand::new(paths, source, f);
+    Driver::new().run(&mut sand);
+    sand
+}
+
+pub fn sand_at_rest(paths: &[Path], source: usize, f: fn(&mut Scan)) -> usize {
+    settle(paths, source, f).units
+}
+
+// The scan once all of the sand is at rest
+pub fn settled_scan(paths: &[Path], source: usize, f: fn(&mut Scan)) -> Scan {
+    settle(paths, source, f).scan
+}
+
+// Commands to drop sand a unit at a time in `aoc repl`
+struct Falling<'a> {
+    paths: &'a [Path],
+    source: usize,
+    part: u8,
+    sand: Sand,
+}
+
+impl Falling<'_> {
+    fn reset(&mut self, part: u8) {
+        let f = if part == 1 {
+            |_: &mut Scan| {}
+        } else {
+            add_floor
+        };
+        self.part = part;
+        self.sand = Sand::new(self.paths, self.source, f);
+    }
+
+    fn status(&self) -> String {
+        let units = self.sand.units;
+        match (self.sand.fallen, self.sand.is_done()) {
+            (true, _) => format!("Sand at rest: {units}, and a unit fell into the abyss\n"),
+            (false, true) => format!("Sand at rest: {units}, blocking the source\n"),
+            (false, false) => format!("Sand at rest: {units}\n"),
+        }
+    }
+}
+
+impl Repl for Falling<'_> {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("step [n]", "Drop n (or 1) units of sand"),
+            ("scan", "Show the scan"),
+            (
+                "reset [part]",
+                "Start again without sand, for part 1 (the abyss) or 2 (the floor)",
+            ),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "step" => {
+                let n = repl::arg_or(args, 0, "n", 1)?;
+                Driver::new().max_steps(n).run(&mut self.sand);
+                Ok(self.status())
+            }
+            "scan" => Ok(self.sand.scan.render(|&c| c)),
+            "reset" => {
+                match repl::arg_or(args, 0, "part", self.part)? {
+                    part @ (1 | 2) => self.reset(part),
+                    part => return Err(format!("Expected part 1 or 2, found {part}")),
+                }
+                Ok(self.status())
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+// The cave for the depth first search, where sand and rock are both just blocked
+struct Cave {
+    blocked: HashSet<(isize, isize)>,
+    lowest: isize,        // The y coordinate of the lowest rock
+    floor: Option<isize>, // The y coordinate of the floor, if there is one
+    units: usize,         // The units of sand at rest so far
+}
+
+impl Cave {
+    fn new(paths: &[Path], floor: bool) -> Self {
+        let mut blocked = HashSet::new();
+        for window in paths.iter().flat_map(|path| path.windows(2)) {
+            let [x0, x1] = [window[0].x.min(window[1].x), window[0].x.max(window[1].x)];
+            let [y0, y1] = [window[0].y.min(window[1].y), window[0].y.max(window[1].y)];
+            for (x, y) in (x0..=x1).flat_map(|x| (y0..=y1).map(move |y| (x, y))) {
+                blocked.insert((x as isize, y as isize));
+            }
+        }
+        let lowest = paths
+            .iter()
+            .flatten()
+            .map(|coord| coord.y)
+            .max()
+            .unwrap_or(0) as isize;
+        Cave {
+            blocked,
+            lowest,
+            floor: floor.then_some(lowest + 2),
+            units: 0,
+        }
+    }
+
+    fn is_blocked(&self, (x, y): (isize, isize)) -> bool {
+        self.floor == Some(y) || self.blocked.contains(&(x, y))
+    }
+
+    // Fill everything below a position with sand, and then the position itself,
+    // returning false if the sand falls into the abyss instead
+    //
+    // Each unit of sand follows the path of the unit before it, up until where
+    // that unit came to rest. So rather than drop every unit from the source,
+    // the search tries down, then down-left, then down-right, and a position
+    // comes to rest once all three are blocked; in the same order as the sand.
+    fn fill(&mut self, (x, y): (isize, isize)) -> bool {
+        if self.floor.is_none() && y > self.lowest {
+            return false;
+        }
+        for next in [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)] {
+            if !self.is_blocked(next) && !self.fill(next) {
+                return false;
+            }
+        }
+        self.blocked.insert((x, y));
+        self.units += 1;
+        true
+    }
+}
+
+fn draw_scan(scan: &Scan) -> Frame {
+    let mut frame = Grid::new(scan.width(), scan.height(), Pixel::new('.', [0; 3]));
+    for (pos, &c) in scan.iter() {
+        let colour = match c {
+            '#' => [130, 130, 130],
+            'o' => [230, 190, 90],
+            '+' => [255, 60, 60],
+            _ => [20, 20, 20],
+        };
+        frame[pos] = Pixel::new(c, colour);
+    }
+    frame
+}
+
+pub fn sand_at_rest_by_dfs(paths: &[Path], source: usize, floor: bool) -> usize {
+    let mut cave = Cave::new(paths, floor);
+    cave.fill((source as isize, 0));
+    cave.units
+}
+
+// For Part 1, simply simulate a sand falling until it reaches a limit.
+//
+// For Part 2, add the floor on the bottom as an extra line. Then simulate
+// the sand as in Part 1 until the source is also blocked.
+//
+// I used a grid that was able to hold enough information about the cave.
+// This made sense as actually, even though the puzzle mentions the floor
+// is infinite, the sand that falls is not; it will always create this
+// pyramid shap (with holes), and thus the shape and size can be predetermined
+// before simulating the sand.
+//
+// An alternatively method to using a grid was to use a recursive function and
+// depth first search. That sounds like an interesting idea, so it is kept as an
+// alternative (see `Cave`), and checked against the grid method.
+pub struct Day14 {
+    pub source: usize, // The x coordinate sand falls from
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Day14 { source: 500 }
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<Path>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_paths(input)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "source" => self.source = config::value(name, value)?,
+            _ => return Err(format!("Day 14 has no parameter {name:?}")),
+        }
+        Ok(())
+    }
+
+    fn part1(&self, paths: &Self::Input) -> usize {
+        sand_at_rest(paths, self.source, |_| {})
+    }
+
+    fn part2(&self, paths: &Self::Input) -> usize {
+        sand_at_rest(paths, self.source, add_floor)
+    }
+
+    fn repl<'a>(&'a self, paths: &'a Self::Input) -> Option<Box<dyn Repl + 'a>> {
+        Some(Box::new(Falling {
+            paths,
+            source: self.source,
+            part: 1,
+            sand: Sand::new(paths, self.source, |_| {}),
+        }))
+    }
+
+    // The scan after each unit of sand comes to rest (and the last one, which
+    // falls into the abyss in part 1)
+    fn visualise(&self, paths: &Self::Input, part: u8, frames: &mut Frames) -> bool {
+        let mut sand = Sand::new(
+            paths,
+            self.source,
+            if part == 1 { |_| {} } else { add_floor },
+        );
+        Driver::new()
+            .on_step(|_, sand: &Sand| frames.step(|| draw_scan(&sand.scan)))
+            .run(&mut sand);
+        frames.finish(|| draw_scan(&sand.scan));
+        true
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        vec![
+            Alternative {
+                name: "dfs",
+                part: 1,
+                solve: |day, paths| sand_at_rest_by_dfs(paths, day.source, false).to_string(),
+            },
+            Alternative {
+                name: "dfs",
+                part: 2,
+                solve: |day, paths| sand_at_rest_by_dfs(paths, day.source, true).to_string(),
+            },
+        ]
+    }
+
+    // `size` paths of rock, scattered below the source
+    //
+    // Note: sand must always end up falling into the abyss in part 1, so caves
+    // which hold all of it (i.e. where the sand fills up to the source) are
+    // thrown away
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let spread = 5 + 2 * size;
+        let source = self.source;
+        loop {
+            let paths = (0..size.max(1))
+                .map(|_| {
+                    let mut coord = Coord::new(
+                        source.saturating_sub(spread) + rng.below(2 * spread + 1),
+                        1 + rng.below(spread),
+                    );
+                    let mut path = vec![coord];
+                    for segment in 0..rng.between(1, 4) {
+                        let length = rng.between(-4, 4) as isize;
+                        coord = match segment % 2 {
+                            0 => coord.offset(length, 0).unwrap_or(Coord::new(0, coord.y)),
+                            _ => coord.offset(0, length).unwrap_or(Coord::new(coord.x, 1)),
+                        };
+                        coord.y = coord.y.max(1);
+                        path.push(coord);
+                    }
+                    path
+                })
+                .collect::<Vec<_>>();
+            if Cave::new(&paths, false).fill((source as isize, 0)) {
+                continue;
+            }
+            let input = paths
+                .iter()
+                .map(|path| {
+                    path.iter()
+                        .map(|coord| format!("{},{}", coord.x, coord.y))
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                        + "\n"
+                })
+                .collect::<String>();
+            return Some(Generated::new(input));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = Day14::default().parse_file("test_input.txt");
+        assert_eq!(Day14::default().part1(&input), 24);
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day14::default().parse_file("test_input.txt");
+        assert_eq!(Day14::default().part2(&input), 93);
+    }
+
+    #[test]
+    fn source_blocked() {
+        // A bowl which holds all of the sand, so none falls into the abyss
+        let input = Day14::default()
+            .parse("495,0 -> 495,5 -> 505,5 -> 505,0\n")
+            .unwrap();
+        assert_eq!(Day14::default().part1(&input), 25);
+        assert_eq!(sand_at_rest_by_dfs(&input, 500, false), 25);
+    }
+
+    #[test]
+    fn visualise() {
+        let input = Day14::default().parse_file("test_input.txt");
+        let frames = common::visualise::frames(&Day14::default(), &input, 1).unwrap();
+        // Note: a frame for each unit at rest, the unit which falls into the
+        // abyss, and the final state
+        assert_eq!(frames.len(), 24 + 1 + 1);
+        let scan = [
+            "...........+............",
+            "........................",
+            "...........o............",
+            "..........ooo...........",
+            ".........#ooo##.........",
+            "........o#ooo#..........",
+            ".......###ooo#..........",
+            ".........oooo#..........",
+            "......o.ooooo#..........",
+            ".....#########..........",
+            "........................",
+            "........................",
+        ];
+        assert_eq!(
+            common::visualise::ascii(&frames[23]),
+            scan.map(|row| row.to_string() + "\n").concat()
+        );
+
+        let frames = common::visualise::frames(&Day14::default(), &input, 2).unwrap();
+        assert_eq!(frames.len(), 93 + 1);
+        assert_eq!(
+            common::visualise::ascii(&frames[92]).matches('o').count(),
+            93
+        );
+    }
+
+    #[test]
+    fn snapshots() {
+        let paths = Day14::default().parse_file("test_input.txt");
+        let scan = settled_scan(&paths, 500, |_| {});
+        common::snapshot::check(Day14::RES_DIR, "test_input_part1", &scan.render(|&c| c));
+        let scan = settled_scan(&paths, 500, add_floor);
+        common::snapshot::check(Day14::RES_DIR, "test_input_part2", &scan.render(|&c| c));
+    }
+
+    #[test]
+    fn repl() {
+        let day = Day14::default();
+        let input = day.parse_file("test_input.txt");
+        let mut repl = day.repl(&input).unwrap();
+        assert_eq!(repl.run("step", &[]).unwrap(), "Sand at rest: 1\n");
+        assert_eq!(repl.run("step", &["4"]).unwrap(), "Sand at rest: 5\n");
+        assert_eq!(
+            repl.run("step", &["100"]).unwrap(),
+            "Sand at rest: 24, and a unit fell into the abyss\n"
+        );
+        assert_eq!(
+            repl.run("scan", &[]).unwrap(),
+            settled_scan(&input, 500, |_| {}).render(|&c| c)
+        );
+        assert_eq!(repl.run("reset", &["2"]).unwrap(), "Sand at rest: 0\n");
+        assert_eq!(
+            repl.run("step", &["100"]).unwrap(),
+            "Sand at rest: 93, blocking the source\n"
+        );
+        assert!(repl.run("reset", &["3"]).is_err());
+        assert!(repl.run("step", &["x"]).is_err());
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(Day14::default);
+    }
+
+    #[test]
+    fn alternatives() {
+        common::differential::check(Day14::default, &["test_input.txt", "input.txt"], 0..50, 10);
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(Day14::default, 0..20, &[0, 1, 10, 30]);
+    }
+}
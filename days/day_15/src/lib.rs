@@ -0,0 +1,679 @@
+use common::differential::Alternative;
+use common::generate::{Generated, Rng};
+use common::repl::{self, Repl};
+use common::visualise::{Canvas, Frames, Pixel};
+use common::{config, parse, Line, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Range;
+
+pub type Coord = (i32, i32);
+pub type Span = [i32; 2];
+
+// Parse a coordinate e.g. "x=2, y=18", where `name` is what the coordinate is of
+fn parse_coord(line: &Line, coord: &str, name: &str) -> Result<Coord, ParseError> {
+    match line.integers::<i32>(coord, &format!("Cannot parse {name} coordinate as i32"))?[..] {
+        [x, y] => Ok((x, y)),
+        _ => Err(line.error(coord, &format!("Expected {name} X and Y coordinates"))),
+    }
+}
+
+// Parse the puzzle input into a report of sensors, and their closest beacons
+pub fn parse_report(s: &str) -> Result<Vec<[Coord; 2]>, ParseError> {
+    parse::lines(s)
+        .map(|line| {
+            let (left, right) = line.split_once(line.text, ":", "Cannot split line by colon")?;
+            Ok([
+                parse_coord(&line, left, "sensor")?,
+                parse_coord(&line, right, "beacon")?,
+            ])
+        })
+        .collect()
+}
+
+fn distance(x0: i32, x1: i32) -> i32 {
+    (x0 - x1).abs()
+}
+
+pub fn manhattan_distance(a: &Coord, b: &Coord) -> i32 {
+    distance(a.0, b.0) + distance(a.1, b.1)
+}
+
+pub fn span(sensor: &Coord, beacon: &Coord, y: i32) -> Option<Span> {
+    let (sx, sy) = sensor;
+    let d = manhattan_distance(sensor, beacon);
+    let dx = d - distance(*sy, y);
+    if dx >= 0 {
+        Some([sx - dx, sx + dx])
+    } else {
+        None
+    }
+}
+
+// Spans can overlap each other, which would affect the couting the
+// positions that cannot contain a beacon. So we need to fix them.
+pub fn handle_overlaps(mut overlapping_spans: Vec<Span>) -> Vec<Span> {
+    overlapping_spans.sort_by_key(|[a0, _]| *a0);
+    let mut spans = Vec::<Span>::new();
+    // Note: no sensor may reach the row at all
+    let Some(&(mut span)) = overlapping_spans.first() else {
+        return spans;
+    };
+    for overlapping_span in overlapping_spans.iter().skip(1) {
+        if span[1] + 1 < overlapping_span[0] {
+            spans.push(span);
+            span = *overlapping_span;
+        } else {
+            span[1] = span[1].max(overlapping_span[1]);
+        }
+    }
+    spans.push(span);
+    spans
+}
+
+pub fn coords_without_beacon(report: &[[Coord; 2]], y: i32) -> i32 {
+    let sensors_and_beacons = report.iter().flatten().collect::<HashSet<_>>();
+    let spans = report
+        .iter()
+        .map(|[sensor, beacon]| span(sensor, beacon, y))
+        .filter(|maybe_span| !maybe_span.is_none())
+        .map(|maybe_span| maybe_span.expect("No span available"))
+        .collect::<Vec<_>>();
+    let spans = handle_overlaps(spans);
+    spans.iter().map(|[x0, x1]| x1 - x0 + 1).sum::<i32>()
+        - sensors_and_beacons
+            .iter()
+            .filter(|item| item.1 == y)
+            .count() as i32
+}
+
+// Commands to look at which positions the sensors cover in `aoc repl`
+struct Sensors<'a> {
+    report: &'a [[Coord; 2]],
+}
+
+impl Repl for Sensors<'_> {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            (
+                "row <y>",
+                "The spans of the row the sensors cover, and how many positions cannot contain a beacon",
+            ),
+            ("covers <x> <y>", "The sensors covering a position"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "row" => {
+                let y = repl::arg::<i32>(args, 0, "y")?;
+                let spans = self
+                    .report
+                    .iter()
+                    .filter_map(|[sensor, beacon]| span(sensor, beacon, y))
+                    .collect::<Vec<_>>();
+                let spans = handle_overlaps(spans)
+                    .iter()
+                    .map(|[x0, x1]| format!("x={x0}..{x1}"))
+                    .collect::<Vec<_>>();
+                Ok(format!(
+                    "Covered: {}\nCannot contain a beacon: {}\n",
+                    if spans.is_empty() {
+                        "nothing".to_string()
+                    } else {
+                        spans.join(", ")
+                    },
+                    coords_without_beacon(self.report, y)
+                ))
+            }
+            "covers" => {
+                let position = (repl::arg(args, 0, "x")?, repl::arg(args, 1, "y")?);
+                let sensors = self
+                    .report
+                    .iter()
+                    .filter(|[sensor, beacon]| {
+                        manhattan_distance(sensor, &position) <= manhattan_distance(sensor, beacon)
+                    })
+                    .map(|[(x, y), beacon]| {
+                        let (reach, distance) = (
+                            manhattan_distance(&(*x, *y), beacon),
+                            manhattan_distance(&(*x, *y), &position),
+                        );
+                        format!("Sensor at x={x}, y={y} (reaches {reach}, at a distance of {distance})\n")
+                    })
+                    .collect::<String>();
+                if sensors.is_empty() {
+                    Ok("Not covered by any sensor\n".to_string())
+                } else {
+                    Ok(sensors)
+                }
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+// Part 2: Idea #1
+//
+// Rather than search through 4m^2 coordinates, use Part 1 to find the
+// X coordinate for us.
+//
+// We are guaranteed that there is only a single position that could
+// be the distress beacon. So this means that most Y coordinates will
+// have 1 or 0 spans; but there will be exactly one Y coordinate with
+// exactly 2 spans, with a break in the middle. This break will be the
+// distress beacon.
+//
+// Note: the spans must be clipped to the bounds first, as there can be
+// breaks outside of the bounds too; and the break can also be at the very
+// edge of the bounds, leaving a single span.
+//
+pub fn find_distress_beacon(report: &[[Coord; 2]], pair: (i32, i32)) -> usize {
+    let (start, end) = pair;
+    for y in start..=end {
+        let spans = report
+            .iter()
+            .filter_map(|[sensor, beacon]| span(sensor, beacon, y))
+            .filter(|[x0, x1]| *x1 >= start && *x0 <= end)
+            .map(|[x0, x1]| [x0.max(start), x1.min(end)])
+            .collect::<Vec<_>>();
+        let x = if spans.is_empty() {
+            start
+        } else {
+            match handle_overlaps(spans)[0] {
+                [x0, _] if x0 > start => start,
+                [_, x1] if x1 < end => x1 + 1,
+                _ => continue,
+            }
+        };
+        return (x as usize) * 4000000 + (y as usize);
+    }
+    0
+}
+
+fn search_borders(
+    sensor: &Coord,
+    beacon: &Coord,
+    report: &[[Coord; 2]],
+    pair: (i32, i32),
+) -> Option<Coord> {
+    let (start, end) = pair;
+    let (sx, sy) = sensor;
+    let d = manhattan_distance(sensor, beacon) + 1;
+    for dxdy in 0..=d {
+        for (x, y) in [
+            (sx + dxdy, sy + d - dxdy),
+            (sx - dxdy, sy + d - dxdy),
+            (sx + dxdy, sy - d + dxdy),
+            (sx - dxdy, sy - d + dxdy),
+        ] {
+            if !(start..=end).contains(&x) || !(start..=end).contains(&y) {
+                continue;
+            }
+            if !report
+                .iter()
+                .any(|[s, b]| manhattan_distance(s, &(x, y)) <= manhattan_distance(s, b))
+            {
+                return Some((x, y));
+            }
+        }
+    }
+    None
+}
+
+// Part 2: Idea #2
+//
+// Rather than search through 4m^2 coordinates, search the borders of
+// each sensor's range.
+//
+// We are guaranteed that there is only a single position that could
+// be the distress beacon. As the distress beacon is outside all the
+// sensors range, this means we can check the positions just outside
+// the borders of each sensor's range to see if that position is the
+// distress beacon.
+//
+// Note: a report which covers every position within the bounds leaves nowhere
+// for the distress beacon to be
+pub fn distress_beacon(report: &[[Coord; 2]], pair: (i32, i32)) -> Result<Coord, String> {
+    report
+        .iter()
+        .find_map(|[sensor, beacon]| search_borders(sensor, beacon, report, pair))
+        .ok_or_else(|| {
+            let (start, end) = pair;
+            format!("No position within {start}..={end} can be the distress beacon")
+        })
+}
+
+pub fn find_distress_beacon_2(report: &[[Coord; 2]], pair: (i32, i32)) -> Result<usize, String> {
+    let (x, y) = distress_beacon(report, pair)?;
+    Ok((x as usize) * 4000000 + (y as usize))
+}
+
+// The tuning frequency of the distress beacon, or why there is none
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frequency(pub Result<usize, String>);
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Ok(frequency) => write!(f, "{frequency}"),
+            Err(error) => write!(f, "none ({error})"),
+        }
+    }
+}
+
+const UNKNOWN: Pixel = Pixel::new('.', [20, 20, 20]);
+const COVERED: Pixel = Pixel::new('#', [40, 70, 120]);
+const SENSOR: Pixel = Pixel::new('S', [80, 220, 80]);
+const BEACON: Pixel = Pixel::new('B', [230, 190, 90]);
+const FOUND: Pixel = Pixel::new('X', [255, 60, 60]);
+
+fn covers([sensor, beacon]: &[Coord; 2], position: Coord) -> bool {
+    manhattan_distance(sensor, &position) <= manhattan_distance(sensor, beacon)
+}
+
+// Draw the positions each sensor covers within `min..=max`, a sensor at a time,
+// and then what was found (drawn by `found`)
+//
+// Note: the real puzzle input is millions of positions across, so each pixel
+// stands for the square of positions beginning at it (see `Canvas`)
+fn draw_report(
+    report: &[[Coord; 2]],
+    (min, max): (Coord, Coord),
+    frames: &mut Frames,
+    found: impl FnOnce(&mut Canvas),
+) {
+    let position = |(x, y): Coord| (x as i64, y as i64);
+    let mut covered = Canvas::new(position(min), position(max), 100, UNKNOWN);
+    let pixels = covered.frame.coords().collect::<Vec<_>>();
+    let draw = |covered: &Canvas, sensors: usize| {
+        let mut canvas = covered.clone();
+        for [sensor, beacon] in &report[..sensors] {
+            canvas.draw(position(*sensor), SENSOR);
+            canvas.draw(position(*beacon), BEACON);
+        }
+        canvas
+    };
+    for (index, sensor) in report.iter().enumerate() {
+        for &pixel in &pixels {
+            let (x, y) = covered.position(pixel);
+            if covers(sensor, (x as i32, y as i32)) {
+                covered.frame[pixel] = COVERED;
+            }
+        }
+        frames.step(|| draw(&covered, index + 1).frame);
+    }
+    let mut canvas = draw(&covered, report.len());
+    found(&mut canvas);
+    frames.finish(|| canvas.frame);
+}
+
+// The positions along a straight line within reach of a sensor, where the line
+// is at `start + step * k` for each `k`, and `step` is one of the eight directions
+fn covered_along(sensor: &Coord, reach: i32, start: Coord, step: Coord) -> Option<Span> {
+    let (a, b) = (sensor.0 - start.0, sensor.1 - start.1);
+    let [low, high] = match step {
+        (dx, 0) => {
+            let reach = reach - b.abs();
+            [dx * a - reach, dx * a + reach]
+        }
+        (0, dy) => {
+            let reach = reach - a.abs();
+            [dy * b - reach, dy * b + reach]
+        }
+        // The distance is |k - a| + |k - b|, i.e. max(|a - b|, |2k - a - b|)
+        (dx, dy) => {
+            let (a, b) = (dx * a, dy * b);
+            if (a - b).abs() > reach {
+                return None;
+            }
+            [
+                (a + b - reach + 1).div_euclid(2),
+                (a + b + reach).div_euclid(2),
+            ]
+        }
+    };
+    Some([low, high]).filter(|[low, high]| low <= high)
+}
+
+// Whether every position within `0..=max` (in both coordinates), other than
+// `distress`, is covered by a sensor
+//
+// There are far too many positions to check one by one for the real puzzle
+// input's bounds. But an uncovered position can be moved sideways (away from
+// `distress`) until it is either just out of reach of a sensor or at the edge
+// of the bounds, staying uncovered all the while. So only the lines just out of
+// reach of each sensor, and the edges, need checking; a span at a time, as in
+// part 1.
+fn is_covered(report: &[[Coord; 2]], max: i32, distress: Coord) -> bool {
+    let edges = [
+        ((0, 0), (1, 0)),
+        ((0, max), (1, 0)),
+        ((0, 0), (0, 1)),
+        ((max, 0), (0, 1)),
+    ]
+    .map(|(start, step)| (start, step, max));
+    let rings = report.iter().flat_map(|[sensor, beacon]| {
+        let (x, y) = *sensor;
+        let d = manhattan_distance(sensor, beacon) + 1;
+        [
+            ((x + d, y), (-1, 1)),
+            ((x, y + d), (-1, -1)),
+            ((x - d, y), (1, -1)),
+            ((x, y - d), (1, 1)),
+        ]
+        .map(|(start, step)| (start, step, d))
+    });
+    edges.into_iter().chain(rings).all(|(start, step, length)| {
+        let position = |k: i32| (start.0 + step.0 * k, start.1 + step.1 * k);
+        let only_distress = |ks: Range<i32>| ks.map(position).take(2).all(|p| p == distress);
+        // Only the part of the line within the bounds
+        let [low, high] = [(start.0, step.0), (start.1, step.1)]
+            .into_iter()
+            .filter(|&(_, step)| step != 0)
+            .map(|(start, step)| {
+                let (a, b) = (-start * step, (max - start) * step);
+                [a.min(b), a.max(b)]
+            })
+            .fold([0, length], |[low, high], [a, b]| [low.max(a), high.min(b)]);
+        let spans = report
+            .iter()
+            .filter_map(|[sensor, beacon]| {
+                covered_along(sensor, manhattan_distance(sensor, beacon), start, step)
+            })
+            .collect::<Vec<_>>();
+        let mut next = low;
+        for [x0, x1] in handle_overlaps(spans) {
+            if !only_distress(next..x0.min(high + 1)) {
+                return false;
+            }
+            next = next.max(x1 + 1);
+        }
+        only_distress(next..high + 1)
+    })
+}
+
+// Sensors to try adding to a report, before giving up on its distress beacon
+const ATTEMPTS: usize = 1000;
+
+// Generate a report where exactly one position within `0..=max` (in both
+// coordinates) could be the distress beacon
+//
+// The distress beacon is picked first. Sensors are then added at random,
+// each with its closest beacon just short of the distress beacon, until every
+// other position is covered. Sensors which would make the report impossible
+// (i.e. with another beacon closer than their own) are left out.
+fn generate_report(rng: &mut Rng, max: i32) -> Vec<[Coord; 2]> {
+    let within = |rng: &mut Rng, low: i32, high: i32| rng.between(low as i64, high as i64) as i32;
+    loop {
+        let distress = (within(rng, 0, max), within(rng, 0, max));
+        let mut report = Vec::<[Coord; 2]>::new();
+        for _ in 0..ATTEMPTS {
+            let sensor = (
+                within(rng, -max / 2, max + max / 2),
+                within(rng, -max / 2, max + max / 2),
+            );
+            let d = manhattan_distance(&sensor, &distress) - 1;
+            if d < 1 {
+                continue;
+            }
+            let dx = within(rng, -d, d);
+            let dy = (d - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+            let beacon = (sensor.0 + dx, sensor.1 + dy);
+            if report.iter().any(|[s, b]| {
+                manhattan_distance(s, &beacon) < manhattan_distance(s, b)
+                    || manhattan_distance(&sensor, b) < d
+            }) {
+                continue;
+            }
+            report.push([sensor, beacon]);
+            if is_covered(&report, max, distress) {
+                return report;
+            }
+        }
+    }
+}
+
+// The row to check in part 1, and the bounds to search within in part 2,
+// differ between the example and the real puzzle input
+pub struct Day15 {
+    pub row: i32,           // The row to count positions without a beacon in, for part 1
+    pub bounds: (i32, i32), // The bounds of both coordinates of the distress beacon, for part 2
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            row: 2000000,
+            bounds: (0, 4000000),
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const RES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+    type Input = Vec<[Coord; 2]>;
+    type Part1 = i32;
+    type Part2 = Frequency;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_report(input)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "row" => self.row = config::value(name, value)?,
+            "bounds" => self.bounds = config::pair(name, value)?,
+            _ => return Err(format!("Day 15 has no parameter {name:?}")),
+        }
+        Ok(())
+    }
+
+    fn part1(&self, report: &Self::Input) -> i32 {
+        coords_without_beacon(report, self.row)
+    }
+
+    fn part2(&self, report: &Self::Input) -> Frequency {
+        Frequency(find_distress_beacon_2(report, self.bounds))
+    }
+
+    fn repl<'a>(&'a self, report: &'a Self::Input) -> Option<Box<dyn Repl + 'a>> {
+        Some(Box::new(Sensors { report }))
+    }
+
+    // Part 1 finds the positions in the row which cannot contain a beacon, out
+    // of every position the sensors cover; part 2 finds the distress beacon,
+    // within the bounds
+    fn visualise(&self, report: &Self::Input, part: u8, frames: &mut Frames) -> bool {
+        if part == 1 {
+            let reach = |[sensor, beacon]: &[Coord; 2]| {
+                let d = manhattan_distance(sensor, beacon);
+                [(sensor.0 - d, sensor.1 - d), (sensor.0 + d, sensor.1 + d)]
+            };
+            let corners = report.iter().flat_map(reach).collect::<Vec<_>>();
+            let min = corners
+                .iter()
+                .fold((i32::MAX, self.row), |(x0, y0), &(x, y)| {
+                    (x0.min(x), y0.min(y))
+                });
+            let max = corners
+                .iter()
+                .fold((i32::MIN, self.row), |(x0, y0), &(x, y)| {
+                    (x0.max(x), y0.max(y))
+                });
+            let beacons = report
+                .iter()
+                .map(|[_, beacon]| *beacon)
+                .collect::<HashSet<_>>();
+            draw_report(report, (min, max), frames, |canvas| {
+                for x in 0..canvas.frame.width() {
+                    let (x, _) = canvas.position(common::Coord::new(x, 0));
+                    let position = (x as i32, self.row);
+                    if !beacons.contains(&position)
+                        && report.iter().any(|sensor| covers(sensor, position))
+                    {
+                        canvas.draw((x, self.row as i64), FOUND);
+                    }
+                }
+            });
+        } else {
+            let (start, end) = self.bounds;
+            let bounds = ((start, start), (end, end));
+            draw_report(report, bounds, frames, |canvas| {
+                if let Ok((x, y)) = distress_beacon(report, self.bounds) {
+                    canvas.draw((x as i64, y as i64), FOUND);
+                }
+            });
+        }
+        true
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        vec![Alternative {
+            name: "idea #1",
+            part: 2,
+            solve: |day, report| find_distress_beacon(report, day.bounds).to_string(),
+        }]
+    }
+
+    // A report with bounds of `0..=size`, and a row within them
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let max = size.max(2) as i32;
+        let input = generate_report(rng, max)
+            .iter()
+            .map(|[(sx, sy), (bx, by)]| {
+                format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+            })
+            .collect::<String>();
+        Some(
+            Generated::new(input)
+                .param("row", rng.between(0, max as i64))
+                .param("bounds", format!("0,{max}")),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: Day15 = Day15 {
+        row: 10,
+        bounds: (0, 20),
+    };
+
+    #[test]
+    fn part1() {
+        let input = EXAMPLE.parse_file("test_input.txt");
+        assert_eq!(EXAMPLE.part1(&input), 26);
+    }
+
+    #[test]
+    fn part2() {
+        let input = EXAMPLE.parse_file("test_input.txt");
+        assert_eq!(EXAMPLE.part2(&input), Frequency(Ok(56000011)));
+        let everywhere = Day15 {
+            row: 0,
+            bounds: (9, 11),
+        };
+        assert_eq!(
+            everywhere.part2(&input).to_string(),
+            "none (No position within 9..=11 can be the distress beacon)"
+        );
+    }
+
+    #[test]
+    fn visualise() {
+        let input = EXAMPLE.parse_file("test_input.txt");
+        let frames = common::visualise::frames(&EXAMPLE, &input, 1).unwrap();
+        assert_eq!(frames.len(), input.len() + 1);
+        let last = common::visualise::ascii(frames.last().unwrap());
+        assert_eq!(last.matches('X').count(), 26);
+
+        let frames = common::visualise::frames(&EXAMPLE, &input, 2).unwrap();
+        let last = frames.last().unwrap();
+        assert_eq!((last.width(), last.height()), (21, 21));
+        assert_eq!(last[common::Coord::new(14, 11)].glyph, 'X');
+        assert_eq!(last.iter().filter(|(_, p)| p.glyph == '.').count(), 0);
+    }
+
+    #[test]
+    fn repl() {
+        let input = EXAMPLE.parse_file("test_input.txt");
+        let mut repl = EXAMPLE.repl(&input).unwrap();
+        assert_eq!(
+            repl.run("row", &["10"]).unwrap(),
+            "Covered: x=-2..24\nCannot contain a beacon: 26\n"
+        );
+        assert_eq!(
+            repl.run("row", &["-100"]).unwrap(),
+            "Covered: nothing\nCannot contain a beacon: 0\n"
+        );
+        assert_eq!(
+            repl.run("covers", &["2", "10"]).unwrap(),
+            concat!(
+                "Sensor at x=8, y=7 (reaches 9, at a distance of 9)\n",
+                "Sensor at x=2, y=0 (reaches 10, at a distance of 10)\n",
+                "Sensor at x=0, y=11 (reaches 3, at a distance of 3)\n",
+            )
+        );
+        assert_eq!(
+            repl.run("covers", &["14", "11"]).unwrap(),
+            "Not covered by any sensor\n"
+        );
+        assert!(repl.run("covers", &["14"]).is_err());
+    }
+
+    #[test]
+    fn answers() {
+        common::answers::check(Day15::default);
+    }
+
+    #[test]
+    fn alternatives() {
+        common::differential::check(Day15::default, &["test_input.txt"], 0..50, 20);
+    }
+
+    #[test]
+    fn covered() {
+        // Against every position, for bounds small enough to check them all
+        let mut rng = Rng::new(0);
+        let mut within = |low: i32, high: i32| rng.between(low as i64, high as i64) as i32;
+        let (mut covered, mut uncovered) = (0, 0);
+        for _ in 0..2000 {
+            let max = within(2, 12);
+            let report = (0..within(1, 6))
+                .map(|_| {
+                    let sensor = (within(-6, 18), within(-6, 18));
+                    [
+                        sensor,
+                        (sensor.0 + within(-12, 12), sensor.1 + within(-12, 12)),
+                    ]
+                })
+                .collect::<Vec<_>>();
+            let distress = (within(0, max), within(0, max));
+            let expected = (0..=max)
+                .flat_map(|x| (0..=max).map(move |y| (x, y)))
+                .filter(|&position| position != distress)
+                .all(|position| report.iter().any(|sensor| covers(sensor, position)));
+            assert_eq!(
+                is_covered(&report, max, distress),
+                expected,
+                "{report:?} within 0..={max}, other than {distress:?}"
+            );
+            if expected {
+                covered += 1;
+            } else {
+                uncovered += 1;
+            }
+        }
+        assert!(covered > 100 && uncovered > 100, "{covered} {uncovered}");
+    }
+
+    #[test]
+    fn generate() {
+        common::generate::check(Day15::default, 0..20, &[0, 1, 10, 30]);
+    }
+}
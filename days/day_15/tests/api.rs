@@ -0,0 +1,35 @@
+use day_15::{
+    coords_without_beacon, distress_beacon, find_distress_beacon, find_distress_beacon_2,
+    handle_overlaps, manhattan_distance, parse_report, span,
+};
+
+// Two sensors sharing a beacon, which cover all of `0..=2` but its far corner
+const REPORT: &str = concat!(
+    "Sensor at x=0, y=0: closest beacon is at x=3, y=0\n",
+    "Sensor at x=5, y=0: closest beacon is at x=3, y=0\n",
+);
+
+#[test]
+fn row() {
+    let report = parse_report(REPORT).unwrap();
+    let [sensor, beacon] = report[1];
+    assert_eq!(manhattan_distance(&sensor, &beacon), 2);
+    assert_eq!(span(&sensor, &beacon, 1), Some([4, 6]));
+    assert_eq!(span(&sensor, &beacon, 3), None);
+    assert_eq!(
+        handle_overlaps(vec![[5, 9], [0, 3], [4, 4], [12, 20], [15, 16]]),
+        [[0, 9], [12, 20]]
+    );
+    // x=-3..=7, but for both sensors and their beacon
+    assert_eq!(coords_without_beacon(&report, 0), 8);
+    assert!(parse_report("Sensor at x=0: closest beacon is at x=3, y=0\n").is_err());
+}
+
+#[test]
+fn distress() {
+    let report = parse_report(REPORT).unwrap();
+    assert_eq!(distress_beacon(&report, (0, 2)), Ok((2, 2)));
+    assert_eq!(find_distress_beacon(&report, (0, 2)), 8000002);
+    assert_eq!(find_distress_beacon_2(&report, (0, 2)), Ok(8000002));
+    assert!(distress_beacon(&report, (0, 1)).is_err());
+}